@@ -0,0 +1,197 @@
+// Generates the attribute constants of `src/attributes.rs` from the `GRB_*_ATTR_*` defines of
+// `gurobi_c.h`, grouped by value type and by the kind of object every attribute belongs to.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+// Attributes whose kind does not follow from the header section they are defined in, e.g. the
+// sensitivity and IIS sections mix variable and constraint attributes, and the solution sections
+// hold per-element values next to model values. Every attribute that is not a model attribute is
+// listed, so the kinds do not depend on the wording of the section comments.
+const KINDS: &[(&str, &str)] = &[
+    ("LB", "Var"),
+    ("UB", "Var"),
+    ("OBJ", "Var"),
+    ("VTYPE", "Var"),
+    ("START", "Var"),
+    ("PSTART", "Var"),
+    ("BRANCHPRIORITY", "Var"),
+    ("VARNAME", "Var"),
+    ("PWLOBJCVX", "Var"),
+    ("VARHINTVAL", "Var"),
+    ("VARHINTPRI", "Var"),
+    ("PARTITION", "Var"),
+    ("POOLIGNORE", "Var"),
+    ("VTAG", "Var"),
+    ("X", "Var"),
+    ("XN", "Var"),
+    ("Xn", "Var"),
+    ("POOLNX", "Var"),
+    ("BARX", "Var"),
+    ("RC", "Var"),
+    ("VDUALNORM", "Var"),
+    ("VBASIS", "Var"),
+    ("UNBDRAY", "Var"),
+    ("OBJN", "Var"),
+    ("SCENNLB", "Var"),
+    ("SCENNUB", "Var"),
+    ("SCENNOBJ", "Var"),
+    ("SCENNX", "Var"),
+    ("SA_OBJLOW", "Var"),
+    ("SA_OBJUP", "Var"),
+    ("SA_LBLOW", "Var"),
+    ("SA_LBUP", "Var"),
+    ("SA_UBLOW", "Var"),
+    ("SA_UBUP", "Var"),
+    ("IIS_LB", "Var"),
+    ("IIS_UB", "Var"),
+    ("IIS_LBFORCE", "Var"),
+    ("IIS_UBFORCE", "Var"),
+    ("RHS", "Constr"),
+    ("DSTART", "Constr"),
+    ("SENSE", "Constr"),
+    ("CONSTRNAME", "Constr"),
+    ("LAZY", "Constr"),
+    ("CTAG", "Constr"),
+    ("PI", "Constr"),
+    ("SLACK", "Constr"),
+    ("CDUALNORM", "Constr"),
+    ("CBASIS", "Constr"),
+    ("BARPI", "Constr"),
+    ("FARKASDUAL", "Constr"),
+    ("SCENNRHS", "Constr"),
+    ("SA_RHSLOW", "Constr"),
+    ("SA_RHSUP", "Constr"),
+    ("IIS_CONSTR", "Constr"),
+    ("IIS_CONSTRFORCE", "Constr"),
+    ("QCRHS", "QConstr"),
+    ("QCSENSE", "QConstr"),
+    ("QCNAME", "QConstr"),
+    ("QCTAG", "QConstr"),
+    ("QCPI", "QConstr"),
+    ("QCSLACK", "QConstr"),
+    ("IIS_QCONSTR", "QConstr"),
+    ("IIS_QCONSTRFORCE", "QConstr"),
+    ("GENCONSTRTYPE", "GenConstr"),
+    ("GENCONSTRNAME", "GenConstr"),
+    ("FUNCPIECES", "GenConstr"),
+    ("FUNCPIECEERROR", "GenConstr"),
+    ("FUNCPIECELENGTH", "GenConstr"),
+    ("FUNCPIECERATIO", "GenConstr"),
+    ("FUNCNONLINEAR", "GenConstr"),
+    ("IIS_GENCONSTR", "GenConstr"),
+    ("IIS_GENCONSTRFORCE", "GenConstr"),
+    ("IIS_SOS", "Sos"),
+    ("IIS_SOSFORCE", "Sos"),
+];
+
+// The attribute types of `src/attributes.rs` by the prefix of their defines.
+const TYPES: &[(&str, &str)] = &[
+    ("GRB_INT_ATTR_", "GRBIntAttr"),
+    ("GRB_DBL_ATTR_", "GRBDblAttr"),
+    ("GRB_STR_ATTR_", "GRBStrAttr"),
+    ("GRB_CHAR_ATTR_", "GRBCharAttr"),
+];
+
+/// The kind of the attributes of a section of the header, from its comment, e.g.
+/// `/* Quadratic constraint attributes */`. Only used for attributes missing from `KINDS`.
+fn section_kind(comment: &str) -> &'static str {
+    let comment = comment.to_lowercase();
+    if comment.contains("batch") {
+        "Batch"
+    } else if comment.contains("quadratic constraint") {
+        "QConstr"
+    } else if comment.contains("general constraint") {
+        "GenConstr"
+    } else if comment.contains("sos") {
+        "Sos"
+    } else if comment.contains("variable") {
+        "Var"
+    } else if comment.contains("constraint") {
+        "Constr"
+    } else {
+        "Model"
+    }
+}
+
+/// The text of a `/* ... */` comment that makes up a whole line.
+fn line_comment(line: &str) -> Option<&str> {
+    line.strip_prefix("/*")?.strip_suffix("*/").map(str::trim)
+}
+
+struct Attribute {
+    name: String,
+    define: String,
+    doc: String,
+}
+
+fn main() {
+    let home = env::var("GUROBI_HOME").expect("GUROBI_HOME must point to the Gurobi installation");
+    let header = PathBuf::from(home).join("include").join("gurobi_c.h");
+    println!("cargo:rerun-if-env-changed=GUROBI_HOME");
+    println!("cargo:rerun-if-changed={}", header.display());
+    let source = fs::read_to_string(&header)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", header.display(), e));
+
+    // (type, kind) -> attributes, in the order of the header
+    let mut groups: BTreeMap<(&str, &str), Vec<Attribute>> = BTreeMap::new();
+    let mut section = "Model";
+    for line in source.lines().map(str::trim) {
+        if let Some(comment) = line_comment(line) {
+            section = section_kind(comment);
+            continue;
+        }
+        let Some(define) = line.strip_prefix("#define") else {
+            continue;
+        };
+        let mut parts = define.trim().splitn(2, char::is_whitespace);
+        let (Some(define), Some(rest)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let Some((prefix, ty)) = TYPES.iter().find(|(prefix, _)| define.starts_with(prefix)) else {
+            continue;
+        };
+        // aliases of other defines have no string value of their own
+        if !rest.trim().starts_with('"') {
+            continue;
+        }
+        let name = &define[prefix.len()..];
+        let doc = rest
+            .split_once("/*")
+            .and_then(|(_, doc)| doc.split_once("*/"))
+            .map(|(doc, _)| doc.trim().to_string())
+            .filter(|doc| !doc.is_empty())
+            .unwrap_or_else(|| rest.trim().split('"').nth(1).unwrap_or(name).to_string());
+        let kind = KINDS
+            .iter()
+            .find(|(attr, _)| *attr == name)
+            .map_or(section, |(_, kind)| *kind);
+        let attributes = groups.entry((ty, kind)).or_default();
+        if attributes.iter().all(|a| a.define != define) {
+            attributes.push(Attribute {
+                name: name.to_string(),
+                define: define.to_string(),
+                doc,
+            });
+        }
+    }
+
+    let mut out = String::from("// Generated by build.rs from gurobi_c.h\n");
+    for ((ty, kind), attributes) in &groups {
+        writeln!(out, "attributes! {{\n    {}<{}> {{", ty, kind).unwrap();
+        for attribute in attributes {
+            writeln!(
+                out,
+                "        #[doc = {:?}]\n        {} => {};",
+                attribute.doc, attribute.name, attribute.define
+            )
+            .unwrap();
+        }
+        out.push_str("    }\n}\n");
+    }
+    let path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("attributes.rs");
+    fs::write(&path, out).unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+}
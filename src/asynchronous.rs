@@ -0,0 +1,96 @@
+// Asynchronous optimization with [GRBoptimizeasync](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/model.html#c.GRBoptimizeasync)
+// and [GRBsync](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/model.html#c.GRBsync).
+//
+// The handle borrows the model mutably until the optimization is finished, so the model cannot be
+// modified (or queried through the regular API) while Gurobi is solving it in the background.
+
+use crate::{
+    error::{check_model_err, GRBError},
+    ffi,
+    model::GRBModel,
+    outcome::SolveOutcome,
+};
+
+/// A running asynchronous optimization, created with `GRBModel::optimize_async`.
+///
+/// Dropping the handle waits for the optimization to finish.
+pub struct AsyncOptimization<'a> {
+    model: &'a mut GRBModel,
+    synced: bool,
+}
+
+impl GRBModel {
+    /// Start optimizing the model in the background and return immediately.
+    pub fn optimize_async(&mut self) -> Result<AsyncOptimization<'_>, GRBError> {
+        self.termination_reason = None;
+        #[cfg(feature = "tracing")]
+        crate::logging::trace_optimize_start(self);
+        let error = unsafe { ffi::GRBoptimizeasync(*self.inner.0) };
+        check_model_err(*self.inner.0, error)?;
+        Ok(AsyncOptimization {
+            model: self,
+            synced: false,
+        })
+    }
+}
+
+impl AsyncOptimization<'_> {
+    /// Check whether the optimization has finished, i.e. the `STATUS` is no longer `INPROGRESS`.
+    pub fn is_done(&self) -> Result<bool, GRBError> {
+        let mut status = 0;
+        let error = unsafe {
+            ffi::GRBgetintattr(
+                *self.model.inner.0,
+                ffi::GRB_INT_ATTR_STATUS.as_ptr(),
+                &mut status,
+            )
+        };
+        check_model_err(*self.model.inner.0, error)?;
+        Ok(status != ffi::GRB_INPROGRESS)
+    }
+
+    /// Request the optimization to stop as soon as possible.
+    pub fn terminate(&self) {
+        unsafe {
+            ffi::GRBterminate(*self.model.inner.0);
+        }
+    }
+
+    /// Block until the optimization has finished and return its outcome, or the error of the
+    /// optimization.
+    pub fn join(mut self) -> Result<SolveOutcome, GRBError> {
+        self.sync()?;
+        self.model.solve_outcome()
+    }
+
+    /// Wait for the optimization to finish without blocking the executor, checking every
+    /// `poll_interval` whether the optimization is done.
+    #[cfg(feature = "tokio")]
+    pub async fn wait(
+        mut self,
+        poll_interval: std::time::Duration,
+    ) -> Result<SolveOutcome, GRBError> {
+        while !self.is_done()? {
+            tokio::time::sleep(poll_interval).await;
+        }
+        self.sync()?;
+        self.model.solve_outcome()
+    }
+
+    fn sync(&mut self) -> Result<(), GRBError> {
+        self.synced = true;
+        let error = unsafe { ffi::GRBsync(*self.model.inner.0) };
+        #[cfg(feature = "tracing")]
+        crate::logging::trace_optimize_end(self.model);
+        check_model_err(*self.model.inner.0, error)
+    }
+}
+
+impl Drop for AsyncOptimization<'_> {
+    fn drop(&mut self) {
+        // the model must not be used again before the background optimization is finished
+        if !self.synced {
+            let _ = self.sync();
+        }
+    }
+}
@@ -1,140 +1,283 @@
 use crate::{
+    batch::{check_batch_err, BatchGetter},
     constr::{ConstrGetter, ConstrSetter},
+    error::{check_model_err, GRBError},
     ffi,
-    model::{ModelGetter, ModelGetterList, ModelSetter, ModelSetterList},
+    model::{
+        ModelGetter, ModelGetterArray, ModelGetterArrayInto, ModelGetterList, ModelSetter,
+        ModelSetterArray, ModelSetterList,
+    },
     modeling::IsModelingObject,
     var::{VariableGetter, VariableSetter},
 };
 use std::{
     ffi::{CStr, CString},
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
     ptr::null_mut,
 };
-#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
-#[derive(Clone, Copy)]
-pub enum GRBIntAttr {
-    /// number of MIP starts
-    NUMSTART,
-    /// number of scenarios
-    NUMSCENARIOS,
-    /// number of objectives
-    NUMOBJ,
-    /// status for a pass during the multi-objective solve
-    OBJPASSNSTATUS,
-    /// optimization pass in which the selected objective function was processed
-    OBJNPASS,
-    /// number of optimization passes during the multi-objective solve
-    NUMOBJPASSES,
-    /// priority
-    OBJNPRIORITY,
-    /// Force general constr to be (1) or to not be (0) in final IIS
-    IIS_GENCONSTRFORCE,
-    /// Force QConstr to be (1) or to not be (0) in final IIS
-    IIS_QCONSTRFORCE,
-    /// Force SOS to be (1) or to not be (0) in final IIS
-    IIS_SOSFORCE,
-    /// Force constr to be (1) or to not be (0) in final IIS
-    IIS_CONSTRFORCE,
-    /// Force var UB to be (1) or to not be (0) in final IIS
-    IIS_UBFORCE,
-    /// Force var LB to be (1) or to not be (0) in final IIS
-    IIS_LBFORCE,
-    /// Boolean: Is general constr in IIS?
-    IIS_GENCONSTR,
-    /// Boolean: Is QConstr in IIS?
-    IIS_QCONSTR,
-    /// Boolean: Is SOS in IIS?
-    IIS_SOS,
-    /// Boolean: Is constr in IIS?
-    IIS_CONSTR,
-    /// Boolean: Is var UB in IIS?
-    IIS_UB,
-    /// Boolean: Is var LB in IIS?
-    IIS_LB,
-    /// Boolean: Is IIS Minimal?
-    IIS_MINIMAL,
-    /// Constraint basis status
-    CBASIS,
-    /// Variable basis status
-    VBASIS,
-    /// method that solved LP using concurrent
-    CONCURRENTWINMETHOD,
-    // 0, no basis,
-    // 1, has basis, so can be computed
-    // 2, available
-    HASDUALNORM,
-    /// Iters performed (NL barrier)
-    NLBARITERCOUNT,
-    /// Iters performed (barrier)
-    BARITERCOUNT,
-    /// Status computed by barrier before crossover
-    BARSTATUS,
-    /// # of solutions found
-    SOLCOUNT,
-    /// Optimization status
-    STATUS,
-    /// An option for PWL translation
-    FUNCNONLINEAR,
-    /// An option for PWL translation
-    FUNCPIECES,
-    /// Type of general constraint
-    GENCONSTRTYPE,
-    /// Lazy constraint?
-    LAZY,
-    /// Ignore variable for solution identity check in solution pool
-    POOLIGNORE,
-    /// user specified variable partition
-    PARTITION,
-    /// variable hint priority
-    VARHINTPRI,
-    /// Convexity of variable PWL obj
-    PWLOBJCVX,
-    /// MIP branch priority
-    BRANCHPRIORITY,
-    /// fingerprint computed from the model data and attributes influencing the optimization process
-    FINGERPRINT,
-    /// number of tagged elements in model
-    NUMTAGGED,
-    /// License expiration date
-    LICENSE_EXPIRATION,
-    /// Model has multiple objectives?
-    IS_MULTIOBJ,
-    /// Model has quadratic constr?
-    IS_QCP,
-    /// Is model a QP/MIQP (without Q/NL constraints)?
-    IS_QP,
-    /// Is model a MIP?
-    IS_MIP,
-    /// 1=min, -1=max
-    MODELSENSE,
-    /// # of variables with PWL obj.
-    NUMPWLOBJVARS,
-    /// # of binary vars
-    NUMBINVARS,
-    /// # of integer vars
-    NUMINTVARS,
-    /// # of nz in q constraints
-    NUMQCNZS,
-    /// # of nz in Q
-    NUMQNZS,
-    /// # of nz in A
-    NUMNZS,
-    /// # of general constraints
-    NUMGENCONSTRS,
-    /// # of quadratic constraints
-    NUMQCONSTRS,
-    /// # of sos constraints
-    NUMSOS,
-    /// # of vars
-    NUMVARS,
-    /// # of constraints
-    NUMCONSTRS,
+
+/// Copy a string returned by gurobi, which stays owned by gurobi.
+fn string_from_ptr(value: *mut std::ffi::c_char) -> String {
+    if value.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(value) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// The kind of object an attribute belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GRBAttrObject {
+    Model,
+    Var,
+    Constr,
+    QConstr,
+    GenConstr,
+    Sos,
+    Batch,
+}
+
+/// The kinds of object as types, e.g. `GRBStrAttr<kind::Var>` is an attribute of variables. An
+/// attribute only implements the getter and setter traits of the handles of its kind, so reading
+/// `VARNAME` through a `GRBConstr` does not compile:
+///
+/// ```compile_fail
+/// use gurobirs::prelude::*;
+///
+/// let env = GRBenv::new(false, None).unwrap();
+/// let mut model = GRBModel::new(&env);
+/// let x = model.add_var(GRBVar::builder());
+/// let c = model.add_constr((1.0 * &x).le(1.0).name("c"));
+/// c.get(GRBStrAttr::VARNAME);
+/// ```
+///
+/// Likewise, the attributes of a batch are only read through a `GRBbatch`:
+///
+/// ```compile_fail
+/// use gurobirs::prelude::*;
+///
+/// let env = GRBenv::new(false, None).unwrap();
+/// let model = GRBModel::new(&env);
+/// model.get(GRBIntAttr::BATCHSTATUS);
+/// ```
+pub mod kind {
+    use super::GRBAttrObject;
+
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// A kind of object attributes belong to.
+    pub trait AttrKind: sealed::Sealed {
+        const OBJECT: GRBAttrObject;
+    }
+
+    /// Kinds with a value per element, which are read and written in bulk with `get_list`,
+    /// `get_array` and `get_all`.
+    pub trait ElementKind: AttrKind {}
+
+    /// Kinds of constraint a `GRBConstr` can refer to. A `GRBConstr` still checks at runtime that
+    /// the attribute belongs to its own kind of constraint.
+    pub trait ConstrKind: ElementKind {}
+
+    macro_rules! kinds {
+        ($($(#[$meta:meta])* $kind:ident: $($bound:ident),*;)*) => {
+            $(
+                $(#[$meta])*
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                pub struct $kind;
+
+                impl sealed::Sealed for $kind {}
+
+                impl AttrKind for $kind {
+                    const OBJECT: GRBAttrObject = GRBAttrObject::$kind;
+                }
+
+                $(impl $bound for $kind {})*
+            )*
+        };
+    }
+
+    kinds! {
+        /// Attributes of the model, read with `GRBModel::get`
+        Model: ;
+        /// Attributes of variables
+        Var: ElementKind;
+        /// Attributes of linear constraints
+        Constr: ElementKind, ConstrKind;
+        /// Attributes of quadratic constraints
+        QConstr: ElementKind, ConstrKind;
+        /// Attributes of general constraints
+        GenConstr: ElementKind, ConstrKind;
+        /// Attributes of SOS constraints
+        Sos: ElementKind;
+        /// Attributes of a batch, read with `GRBbatch::get`
+        Batch: ;
+    }
+}
+
+use kind::{AttrKind, ConstrKind, ElementKind};
+
+/// Reject attributes that are read or written through a constraint of another kind, e.g. `QCRHS`
+/// on a linear constraint, which gurobi would silently resolve to the quadratic constraint with the
+/// same index.
+fn check_object(
+    attr_name: &CStr,
+    object: GRBAttrObject,
+    allowed: &[GRBAttrObject],
+) -> Result<(), GRBError> {
+    if allowed.contains(&object) {
+        Ok(())
+    } else {
+        Err(GRBError::new(
+            ffi::GRB_ERROR_UNKNOWN_ATTRIBUTE,
+            format!(
+                "attribute {} belongs to {:?} objects",
+                attr_name.to_string_lossy(),
+                object
+            ),
+        ))
+    }
+}
+
+/// Reject handles in a list access that are not of the kind the attribute belongs to, e.g. a
+/// quadratic constraint in a list for `RHS`, whose index gurobi would resolve to a linear
+/// constraint.
+fn check_handles<C: IsModelingObject>(
+    attr_name: &CStr,
+    object: GRBAttrObject,
+    handles: &[C],
+) -> Result<(), GRBError> {
+    match handles.iter().find(|handle| handle.object() != object) {
+        Some(handle) => Err(GRBError::new(
+            ffi::GRB_ERROR_INVALID_ARGUMENT,
+            format!(
+                "attribute {} belongs to {:?} objects, not to {:?} {}",
+                attr_name.to_string_lossy(),
+                object,
+                handle.object(),
+                handle.index()
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+// Defines an attribute type, generic over the kind of object its attributes belong to.
+macro_rules! attribute_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        pub struct $name<K> {
+            name: &'static CStr,
+            kind: PhantomData<K>,
+        }
+
+        impl<K> $name<K> {
+            const fn new(name: &'static CStr) -> Self {
+                Self {
+                    name,
+                    kind: PhantomData,
+                }
+            }
+        }
+
+        impl<K: AttrKind> $name<K> {
+            /// The kind of object this attribute belongs to.
+            pub fn object(self) -> GRBAttrObject {
+                K::OBJECT
+            }
+        }
+
+        // implemented by hand, derives would require the kind to implement the traits as well
+        impl<K> Clone for $name<K> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<K> Copy for $name<K> {}
+
+        impl<K> PartialEq for $name<K> {
+            fn eq(&self, other: &Self) -> bool {
+                self.name == other.name
+            }
+        }
+
+        impl<K> Eq for $name<K> {}
+
+        impl<K> Hash for $name<K> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.name.hash(state);
+            }
+        }
+
+        impl<K> fmt::Debug for $name<K> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.name.to_string_lossy())
+            }
+        }
+
+        impl<K> From<$name<K>> for &'static CStr {
+            fn from(value: $name<K>) -> &'static CStr {
+                value.name
+            }
+        }
+    };
+}
+
+attribute_type! {
+    /// An integer attribute, e.g. `GRBIntAttr::STATUS` of the model or `GRBIntAttr::VBASIS` of a
+    /// variable.
+    GRBIntAttr
 }
 
-impl ConstrSetter for GRBIntAttr {
+attribute_type! {
+    /// A double attribute, e.g. `GRBDblAttr::OBJVAL` of the model or `GRBDblAttr::X` of a variable.
+    GRBDblAttr
+}
+
+attribute_type! {
+    /// A string attribute, e.g. `GRBStrAttr::MODELNAME` of the model or `GRBStrAttr::VARNAME` of
+    /// a variable.
+    GRBStrAttr
+}
+
+attribute_type! {
+    /// A char attribute, e.g. `GRBCharAttr::VTYPE` of a variable.
+    GRBCharAttr
+}
+
+// Defines the attributes of one type and kind from `NAME => C constant;` entries, generated by
+// `build.rs` from the defines of `gurobi_c.h`.
+macro_rules! attributes {
+    (
+        $name:ident<$kind:ident> {
+            $($(#[$meta:meta])* $attr:ident => $cname:ident;)*
+        }
+    ) => {
+        #[allow(non_upper_case_globals)]
+        impl $name<kind::$kind> {
+            $($(#[$meta])* pub const $attr: Self = Self::new(ffi::$cname);)*
+
+            /// All attributes of this type that belong to this kind of object.
+            pub const ALL: &'static [Self] = &[$(Self::$attr,)*];
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/attributes.rs"));
+
+impl<K: ConstrKind> ConstrSetter for GRBIntAttr<K> {
     type Value = i32;
 
     fn set(&self, constr: &crate::constr::GRBConstr, value: Self::Value) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if constr.kind != K::OBJECT {
+            return ffi::GRB_ERROR_UNKNOWN_ATTRIBUTE;
+        }
         let value = value as std::ffi::c_int;
         unsafe {
             ffi::GRBsetintattrelement(
@@ -147,45 +290,46 @@ impl ConstrSetter for GRBIntAttr {
     }
 }
 
-impl ConstrGetter for GRBIntAttr {
+impl<K: ConstrKind> ConstrGetter for GRBIntAttr<K> {
     type Value = i32;
 
-    fn get(&self, constr: &crate::prelude::GRBConstr) -> Self::Value {
+    fn get(&self, constr: &crate::constr::GRBConstr) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let return_ptr = 0;
+        check_object(attr_name, K::OBJECT, &[constr.kind])?;
+        let mut value: std::ffi::c_int = 0;
         let error = unsafe {
             ffi::GRBgetintattrelement(
                 *constr.inner.0,
                 attr_name.as_ptr(),
                 constr.index() as std::ffi::c_int,
-                return_ptr as *mut std::ffi::c_int,
+                &mut value,
             )
         };
-        constr.get_error(error).unwrap();
-        return_ptr
+        check_model_err(*constr.inner.0, error)?;
+        Ok(value)
     }
 }
 
-impl VariableGetter for GRBIntAttr {
+impl VariableGetter for GRBIntAttr<kind::Var> {
     type Value = i32;
 
-    fn get(&self, var: &crate::prelude::GRBVar) -> Self::Value {
+    fn get(&self, var: &crate::var::GRBVar) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let return_ptr = 0;
+        let mut value: std::ffi::c_int = 0;
         let error = unsafe {
             ffi::GRBgetintattrelement(
                 *var.inner.0,
                 attr_name.as_ptr(),
                 var.index() as std::ffi::c_int,
-                return_ptr as *mut std::ffi::c_int,
+                &mut value,
             )
         };
-        var.get_error(error).unwrap();
-        return_ptr
+        check_model_err(*var.inner.0, error)?;
+        Ok(value)
     }
 }
 
-impl VariableSetter for GRBIntAttr {
+impl VariableSetter for GRBIntAttr<kind::Var> {
     type Value = i32;
 
     fn set(&self, var: &crate::prelude::GRBVar, value: Self::Value) -> i32 {
@@ -201,20 +345,19 @@ impl VariableSetter for GRBIntAttr {
     }
 }
 
-impl ModelGetter for GRBIntAttr {
+impl ModelGetter for GRBIntAttr<kind::Model> {
     type Value = i32;
 
-    fn get(&self, model: *mut ffi::GRBmodel) -> Self::Value {
-        let mut value_p = 0;
+    fn get(&self, model: *mut ffi::GRBmodel) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let error = unsafe {
-            ffi::GRBgetintattr(model, attr_name.as_ptr(), value_p as *mut std::ffi::c_int)
-        };
-        value_p
+        let mut value: std::ffi::c_int = 0;
+        let error = unsafe { ffi::GRBgetintattr(model, attr_name.as_ptr(), &mut value) };
+        check_model_err(model, error)?;
+        Ok(value)
     }
 }
 
-impl ModelSetter for GRBIntAttr {
+impl ModelSetter for GRBIntAttr<kind::Model> {
     type Value = i32;
 
     fn set(&self, model: *mut ffi::GRBmodel, value: Self::Value) -> i32 {
@@ -223,47 +366,68 @@ impl ModelSetter for GRBIntAttr {
     }
 }
 
-impl<C> ModelSetterList<C> for GRBIntAttr
+impl BatchGetter for GRBIntAttr<kind::Batch> {
+    type Value = i32;
+
+    fn get(&self, batch: *mut ffi::GRBbatch) -> Result<Self::Value, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        let mut value: std::ffi::c_int = 0;
+        let error = unsafe { ffi::GRBgetbatchintattr(batch, attr_name.as_ptr(), &mut value) };
+        check_batch_err(batch, error)?;
+        Ok(value)
+    }
+}
+
+impl<K, C> ModelSetterList<C> for GRBIntAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = i32;
 
-    fn set_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>, values: Vec<Self::Value>) -> i32 {
+    fn set_list(&self, model: *mut ffi::GRBmodel, inds: &[C], values: &[Self::Value]) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if inds.len() != values.len() || check_handles(attr_name, K::OBJECT, inds).is_err() {
+            return ffi::GRB_ERROR_INVALID_ARGUMENT;
+        }
         let len = values.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
-        let mut values = values;
         unsafe {
             ffi::GRBsetintattrlist(
                 model,
                 attr_name.as_ptr(),
                 len as std::ffi::c_int,
                 inds.as_mut_ptr(),
-                values.as_mut_ptr(),
+                values.as_ptr() as *mut _,
             )
         }
     }
 }
 
-impl<C> ModelGetterList<C> for GRBIntAttr
+impl<K, C> ModelGetterList<C> for GRBIntAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = i32;
 
-    fn get_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>) -> Vec<Self::Value> {
+    fn get_list(
+        &self,
+        model: *mut ffi::GRBmodel,
+        inds: &[C],
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_handles(attr_name, K::OBJECT, inds)?;
         let len = inds.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
         let mut values = vec![0 as std::ffi::c_int; len];
-        let attr_name: &CStr = (*self).into();
-        unsafe {
+        let error = unsafe {
             ffi::GRBgetintattrlist(
                 model,
                 attr_name.as_ptr(),
@@ -272,240 +436,19 @@ where
                 values.as_mut_ptr(),
             )
         };
-        values
-    }
-}
-impl From<GRBIntAttr> for &'static CStr {
-    fn from(value: GRBIntAttr) -> &'static CStr {
-        match value {
-            GRBIntAttr::NUMSTART => ffi::GRB_INT_ATTR_NUMSTART,
-            GRBIntAttr::NUMSCENARIOS => ffi::GRB_INT_ATTR_NUMSCENARIOS,
-            GRBIntAttr::NUMOBJ => ffi::GRB_INT_ATTR_NUMOBJ,
-            GRBIntAttr::OBJPASSNSTATUS => ffi::GRB_INT_ATTR_OBJPASSNSTATUS,
-            GRBIntAttr::OBJNPASS => ffi::GRB_INT_ATTR_OBJNPASS,
-            GRBIntAttr::NUMOBJPASSES => ffi::GRB_INT_ATTR_NUMOBJPASSES,
-            GRBIntAttr::OBJNPRIORITY => ffi::GRB_INT_ATTR_OBJNPRIORITY,
-            GRBIntAttr::IIS_GENCONSTRFORCE => ffi::GRB_INT_ATTR_IIS_GENCONSTRFORCE,
-            GRBIntAttr::IIS_QCONSTRFORCE => ffi::GRB_INT_ATTR_IIS_QCONSTRFORCE,
-            GRBIntAttr::IIS_SOSFORCE => ffi::GRB_INT_ATTR_IIS_SOSFORCE,
-            GRBIntAttr::IIS_CONSTRFORCE => ffi::GRB_INT_ATTR_IIS_CONSTRFORCE,
-            GRBIntAttr::IIS_UBFORCE => ffi::GRB_INT_ATTR_IIS_UBFORCE,
-            GRBIntAttr::IIS_LBFORCE => ffi::GRB_INT_ATTR_IIS_LBFORCE,
-            GRBIntAttr::IIS_GENCONSTR => ffi::GRB_INT_ATTR_IIS_GENCONSTR,
-            GRBIntAttr::IIS_QCONSTR => ffi::GRB_INT_ATTR_IIS_QCONSTR,
-            GRBIntAttr::IIS_SOS => ffi::GRB_INT_ATTR_IIS_SOS,
-            GRBIntAttr::IIS_CONSTR => ffi::GRB_INT_ATTR_IIS_CONSTR,
-            GRBIntAttr::IIS_UB => ffi::GRB_INT_ATTR_IIS_UB,
-            GRBIntAttr::IIS_LB => ffi::GRB_INT_ATTR_IIS_LB,
-            GRBIntAttr::IIS_MINIMAL => ffi::GRB_INT_ATTR_IIS_MINIMAL,
-            GRBIntAttr::CBASIS => ffi::GRB_INT_ATTR_CBASIS,
-            GRBIntAttr::VBASIS => ffi::GRB_INT_ATTR_VBASIS,
-            GRBIntAttr::CONCURRENTWINMETHOD => ffi::GRB_INT_ATTR_CONCURRENTWINMETHOD,
-            GRBIntAttr::HASDUALNORM => ffi::GRB_INT_ATTR_HASDUALNORM,
-            GRBIntAttr::NLBARITERCOUNT => ffi::GRB_INT_ATTR_NLBARITERCOUNT,
-            GRBIntAttr::BARITERCOUNT => ffi::GRB_INT_ATTR_BARITERCOUNT,
-            GRBIntAttr::BARSTATUS => ffi::GRB_INT_ATTR_BARSTATUS,
-            GRBIntAttr::SOLCOUNT => ffi::GRB_INT_ATTR_SOLCOUNT,
-            GRBIntAttr::STATUS => ffi::GRB_INT_ATTR_STATUS,
-            GRBIntAttr::FUNCNONLINEAR => ffi::GRB_INT_ATTR_FUNCNONLINEAR,
-            GRBIntAttr::FUNCPIECES => ffi::GRB_INT_ATTR_FUNCPIECES,
-            GRBIntAttr::GENCONSTRTYPE => ffi::GRB_INT_ATTR_GENCONSTRTYPE,
-            GRBIntAttr::LAZY => ffi::GRB_INT_ATTR_LAZY,
-            GRBIntAttr::POOLIGNORE => ffi::GRB_INT_ATTR_POOLIGNORE,
-            GRBIntAttr::PARTITION => ffi::GRB_INT_ATTR_PARTITION,
-            GRBIntAttr::VARHINTPRI => ffi::GRB_INT_ATTR_VARHINTPRI,
-            GRBIntAttr::PWLOBJCVX => ffi::GRB_INT_ATTR_PWLOBJCVX,
-            GRBIntAttr::BRANCHPRIORITY => ffi::GRB_INT_ATTR_BRANCHPRIORITY,
-            GRBIntAttr::FINGERPRINT => ffi::GRB_INT_ATTR_FINGERPRINT,
-            GRBIntAttr::NUMTAGGED => ffi::GRB_INT_ATTR_NUMTAGGED,
-            GRBIntAttr::LICENSE_EXPIRATION => ffi::GRB_INT_ATTR_LICENSE_EXPIRATION,
-            GRBIntAttr::IS_MULTIOBJ => ffi::GRB_INT_ATTR_IS_MULTIOBJ,
-            GRBIntAttr::IS_QCP => ffi::GRB_INT_ATTR_IS_QCP,
-            GRBIntAttr::IS_QP => ffi::GRB_INT_ATTR_IS_QP,
-            GRBIntAttr::IS_MIP => ffi::GRB_INT_ATTR_IS_MIP,
-            GRBIntAttr::MODELSENSE => ffi::GRB_INT_ATTR_MODELSENSE,
-            GRBIntAttr::NUMPWLOBJVARS => ffi::GRB_INT_ATTR_NUMPWLOBJVARS,
-            GRBIntAttr::NUMBINVARS => ffi::GRB_INT_ATTR_NUMBINVARS,
-            GRBIntAttr::NUMINTVARS => ffi::GRB_INT_ATTR_NUMINTVARS,
-            GRBIntAttr::NUMQCNZS => ffi::GRB_INT_ATTR_NUMQCNZS,
-            GRBIntAttr::NUMQNZS => ffi::GRB_INT_ATTR_NUMQNZS,
-            GRBIntAttr::NUMNZS => ffi::GRB_INT_ATTR_NUMNZS,
-            GRBIntAttr::NUMGENCONSTRS => ffi::GRB_INT_ATTR_NUMGENCONSTRS,
-            GRBIntAttr::NUMQCONSTRS => ffi::GRB_INT_ATTR_NUMQCONSTRS,
-            GRBIntAttr::NUMSOS => ffi::GRB_INT_ATTR_NUMSOS,
-            GRBIntAttr::NUMVARS => ffi::GRB_INT_ATTR_NUMVARS,
-            GRBIntAttr::NUMCONSTRS => ffi::GRB_INT_ATTR_NUMCONSTRS,
-        }
+        check_model_err(model, error)?;
+        Ok(values)
     }
 }
 
-#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
-#[derive(Clone, Copy)]
-pub enum GRBDblAttr {
-    /// Deprecated since v13 - use POOLNX instead
-    Xn,
-    /// maximum amount of allocated memory (in GB) in master environment
-    MAXMEMUSED,
-    /// current amount of allocated memory (in GB) in master environment
-    MEMUSED,
-    /// objective value for scenario i
-    SCENNOBJVAL,
-    /// objective bound for scenario i
-    SCENNOBJBOUND,
-    /// solution value in scenario i
-    SCENNX,
-    /// right hand side in scenario i
-    SCENNRHS,
-    /// objective in scenario i
-    SCENNOBJ,
-    /// upper bound in scenario i
-    SCENNUB,
-    /// lower bound in scenario i
-    SCENNLB,
-    /// work done for a pass during the multi-objective solve
-    OBJPASSNWORK,
-    /// runtime for a pass during the multi-objective solve
-    OBJPASSNRUNTIME,
-    /// number of unexplored nodes for a pass during the multi-objective solve
-    OBJPASSNOPENNODECOUNT,
-    /// objective value for a pass during the multi-objective solve
-    OBJPASSNOBJVAL,
-    /// objective bound for a pass during the multi-objective solve
-    OBJPASSNOBJBOUND,
-    /// number of explored nodes for a pass during the multi-objective solve
-    OBJPASSNNODECOUNT,
-    /// MIP gap for a pass during the multi-objective solve
-    OBJPASSNMIPGAP,
-    /// simplex iteration count for a pass during the multi-objective solve
-    OBJPASSNITERCOUNT,
-    /// absolute tolerance
-    OBJNABSTOL,
-    /// relative tolerance
-    OBJNRELTOL,
-    /// weight
-    OBJNWEIGHT,
-    /// constant term
-    OBJNCON,
-    /// Solution objective for Multi-objectives, also depends on solutionnumber
-    OBJNVAL,
-    /// ith objective
-    OBJN,
-    /// Dual norm square
-    CDUALNORM,
-    /// QC Constraint slack
-    QCSLACK,
-    /// Constraint slack
-    SLACK,
-    /// Dual value for QC
-    QCPI,
-    /// Dual value
-    PI,
-    /// Dual norm square
-    VDUALNORM,
-    /// Reduced costs
-    RC,
-    /// Best barrier dual iterate
-    BARPI,
-    /// Best barrier primal iterate
-    BARX,
-    /// Deprecated since v13 - use POOLNX instead
-    XN,
-    /// Alternate MIP solution, depends on solutionnumber
-    POOLNX,
-    /// Solution value
-    X,
-    /// Unexplored nodes (B&C)
-    OPENNODECOUNT,
-    /// Nodes explored (B&C)
-    NODECOUNT,
-    /// Iters performed (PDHG)
-    PDHGITERCOUNT,
-    /// Iters performed (simplex)
-    ITERCOUNT,
-    /// MIP optimality gap
-    MIPGAP,
-    /// Deprecated since v13 - use POOLNOBJVAL instead
-    POOLOBJVAL,
-    /// Solution objective, depends on solutionnumber
-    POOLNOBJVAL,
-    /// Best bound on pool solution
-    POOLOBJBOUND,
-    /// Continuous bound
-    OBJBOUNDC,
-    /// Best bound on solution
-    OBJBOUND,
-    /// Solution objective
-    OBJVAL,
-    /// Work for optimization
-    WORK,
-    /// Run time for optimization
-    RUNTIME,
-    /// Min (abs) rhs of Q
-    MIN_QCRHS,
-    /// Max (abs) rhs of Q
-    MAX_QCRHS,
-    /// Min (abs) nz coeff in linear part of Q
-    MIN_QCLCOEFF,
-    /// Max (abs) nz coeff in linear part of Q
-    MAX_QCLCOEFF,
-    /// Min (abs) obj coeff of quadratic part
-    MIN_QOBJ_COEFF,
-    /// Max (abs) obj coeff of quadratic part
-    MAX_QOBJ_COEFF,
-    /// Min (abs) nz coeff in Q
-    MIN_QCCOEFF,
-    /// Max (abs) nz coeff in Q
-    MAX_QCCOEFF,
-    /// Min (abs) rhs coeff
-    MIN_RHS,
-    /// Max (abs) rhs coeff
-    MAX_RHS,
-    /// Min (abs) obj coeff
-    MIN_OBJ_COEFF,
-    /// Max (abs) obj coeff
-    MAX_OBJ_COEFF,
-    /// Min (abs) var bd
-    MIN_BOUND,
-    /// Max (abs) finite var bd
-    MAX_BOUND,
-    /// Min (abs) nz coeff in A
-    MIN_COEFF,
-    /// Max (abs) nz coeff in A
-    MAX_COEFF,
-    /// An option for PWL translation
-    FUNCPIECERATIO,
-    /// An option for PWL translation
-    FUNCPIECELENGTH,
-    /// An option for PWL translation
-    FUNCPIECEERROR,
-    /// QC RHS
-    QCRHS,
-    /// LP dual solution warm start
-    DSTART,
-    /// RHS
-    RHS,
-    /// variable hint value
-    VARHINTVAL,
-    /// LP primal solution warm start
-    PSTART,
-    /// MIP start value, depends on startnumber
-    START,
-    /// Objective coeff
-    OBJ,
-    /// Upper bound
-    UB,
-    /// Lower bound
-    LB,
-    /// Objective constant
-    OBJCON,
-    /// # of nz in A
-    DNUMNZS,
-}
-impl ConstrSetter for GRBDblAttr {
+impl<K: ConstrKind> ConstrSetter for GRBDblAttr<K> {
     type Value = f64;
 
     fn set(&self, constr: &crate::constr::GRBConstr, value: Self::Value) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if constr.kind != K::OBJECT {
+            return ffi::GRB_ERROR_UNKNOWN_ATTRIBUTE;
+        }
         let value = value as std::ffi::c_double;
         unsafe {
             ffi::GRBsetdblattrelement(
@@ -518,45 +461,46 @@ impl ConstrSetter for GRBDblAttr {
     }
 }
 
-impl ConstrGetter for GRBDblAttr {
+impl<K: ConstrKind> ConstrGetter for GRBDblAttr<K> {
     type Value = f64;
 
-    fn get(&self, constr: &crate::prelude::GRBConstr) -> Self::Value {
+    fn get(&self, constr: &crate::constr::GRBConstr) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let mut return_ptr = 0.0;
+        check_object(attr_name, K::OBJECT, &[constr.kind])?;
+        let mut value: std::ffi::c_double = 0.0;
         let error = unsafe {
             ffi::GRBgetdblattrelement(
                 *constr.inner.0,
                 attr_name.as_ptr(),
                 constr.index() as std::ffi::c_int,
-                &mut return_ptr as *mut std::ffi::c_double,
+                &mut value,
             )
         };
-        constr.get_error(error).unwrap();
-        return_ptr
+        check_model_err(*constr.inner.0, error)?;
+        Ok(value)
     }
 }
 
-impl VariableGetter for GRBDblAttr {
+impl VariableGetter for GRBDblAttr<kind::Var> {
     type Value = f64;
 
-    fn get(&self, var: &crate::prelude::GRBVar) -> Self::Value {
+    fn get(&self, var: &crate::var::GRBVar) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let mut return_ptr = 0.0;
+        let mut value: std::ffi::c_double = 0.0;
         let error = unsafe {
             ffi::GRBgetdblattrelement(
                 *var.inner.0,
                 attr_name.as_ptr(),
                 var.index() as std::ffi::c_int,
-                &mut return_ptr as *mut std::ffi::c_double,
+                &mut value,
             )
         };
-        var.get_error(error).unwrap();
-        return_ptr
+        check_model_err(*var.inner.0, error)?;
+        Ok(value)
     }
 }
 
-impl VariableSetter for GRBDblAttr {
+impl VariableSetter for GRBDblAttr<kind::Var> {
     type Value = f64;
 
     fn set(&self, var: &crate::prelude::GRBVar, value: Self::Value) -> i32 {
@@ -572,24 +516,19 @@ impl VariableSetter for GRBDblAttr {
     }
 }
 
-impl ModelGetter for GRBDblAttr {
+impl ModelGetter for GRBDblAttr<kind::Model> {
     type Value = f64;
 
-    fn get(&self, model: *mut ffi::GRBmodel) -> Self::Value {
-        let mut value_p = 0.0;
+    fn get(&self, model: *mut ffi::GRBmodel) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let error = unsafe {
-            ffi::GRBgetdblattr(
-                model,
-                attr_name.as_ptr(),
-                &mut value_p as *mut std::ffi::c_double,
-            )
-        };
-        value_p
+        let mut value: std::ffi::c_double = 0.0;
+        let error = unsafe { ffi::GRBgetdblattr(model, attr_name.as_ptr(), &mut value) };
+        check_model_err(model, error)?;
+        Ok(value)
     }
 }
 
-impl ModelSetter for GRBDblAttr {
+impl ModelSetter for GRBDblAttr<kind::Model> {
     type Value = f64;
 
     fn set(&self, model: *mut ffi::GRBmodel, value: Self::Value) -> i32 {
@@ -598,47 +537,56 @@ impl ModelSetter for GRBDblAttr {
     }
 }
 
-impl<C> ModelSetterList<C> for GRBDblAttr
+impl<K, C> ModelSetterList<C> for GRBDblAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = f64;
 
-    fn set_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>, values: Vec<Self::Value>) -> i32 {
+    fn set_list(&self, model: *mut ffi::GRBmodel, inds: &[C], values: &[Self::Value]) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if inds.len() != values.len() || check_handles(attr_name, K::OBJECT, inds).is_err() {
+            return ffi::GRB_ERROR_INVALID_ARGUMENT;
+        }
         let len = values.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
-        let mut values = values;
         unsafe {
             ffi::GRBsetdblattrlist(
                 model,
                 attr_name.as_ptr(),
                 len as std::ffi::c_int,
                 inds.as_mut_ptr(),
-                values.as_mut_ptr(),
+                values.as_ptr() as *mut _,
             )
         }
     }
 }
 
-impl<C> ModelGetterList<C> for GRBDblAttr
+impl<K, C> ModelGetterList<C> for GRBDblAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = f64;
 
-    fn get_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>) -> Vec<Self::Value> {
+    fn get_list(
+        &self,
+        model: *mut ffi::GRBmodel,
+        inds: &[C],
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_handles(attr_name, K::OBJECT, inds)?;
         let len = inds.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
         let mut values = vec![0.0 as std::ffi::c_double; len];
-        let attr_name: &CStr = (*self).into();
-        unsafe {
+        let error = unsafe {
             ffi::GRBgetdblattrlist(
                 model,
                 attr_name.as_ptr(),
@@ -647,125 +595,19 @@ where
                 values.as_mut_ptr(),
             )
         };
-        values
-    }
-}
-impl From<GRBDblAttr> for &'static CStr {
-    fn from(value: GRBDblAttr) -> &'static CStr {
-        match value {
-            GRBDblAttr::Xn => ffi::GRB_DBL_ATTR_Xn,
-            GRBDblAttr::MAXMEMUSED => ffi::GRB_DBL_ATTR_MAXMEMUSED,
-            GRBDblAttr::MEMUSED => ffi::GRB_DBL_ATTR_MEMUSED,
-            GRBDblAttr::SCENNOBJVAL => ffi::GRB_DBL_ATTR_SCENNOBJVAL,
-            GRBDblAttr::SCENNOBJBOUND => ffi::GRB_DBL_ATTR_SCENNOBJBOUND,
-            GRBDblAttr::SCENNX => ffi::GRB_DBL_ATTR_SCENNX,
-            GRBDblAttr::SCENNRHS => ffi::GRB_DBL_ATTR_SCENNRHS,
-            GRBDblAttr::SCENNOBJ => ffi::GRB_DBL_ATTR_SCENNOBJ,
-            GRBDblAttr::SCENNUB => ffi::GRB_DBL_ATTR_SCENNUB,
-            GRBDblAttr::SCENNLB => ffi::GRB_DBL_ATTR_SCENNLB,
-            GRBDblAttr::OBJPASSNWORK => ffi::GRB_DBL_ATTR_OBJPASSNWORK,
-            GRBDblAttr::OBJPASSNRUNTIME => ffi::GRB_DBL_ATTR_OBJPASSNRUNTIME,
-            GRBDblAttr::OBJPASSNOPENNODECOUNT => ffi::GRB_DBL_ATTR_OBJPASSNOPENNODECOUNT,
-            GRBDblAttr::OBJPASSNOBJVAL => ffi::GRB_DBL_ATTR_OBJPASSNOBJVAL,
-            GRBDblAttr::OBJPASSNOBJBOUND => ffi::GRB_DBL_ATTR_OBJPASSNOBJBOUND,
-            GRBDblAttr::OBJPASSNNODECOUNT => ffi::GRB_DBL_ATTR_OBJPASSNNODECOUNT,
-            GRBDblAttr::OBJPASSNMIPGAP => ffi::GRB_DBL_ATTR_OBJPASSNMIPGAP,
-            GRBDblAttr::OBJPASSNITERCOUNT => ffi::GRB_DBL_ATTR_OBJPASSNITERCOUNT,
-            GRBDblAttr::OBJNABSTOL => ffi::GRB_DBL_ATTR_OBJNABSTOL,
-            GRBDblAttr::OBJNRELTOL => ffi::GRB_DBL_ATTR_OBJNRELTOL,
-            GRBDblAttr::OBJNWEIGHT => ffi::GRB_DBL_ATTR_OBJNWEIGHT,
-            GRBDblAttr::OBJNCON => ffi::GRB_DBL_ATTR_OBJNCON,
-            GRBDblAttr::OBJNVAL => ffi::GRB_DBL_ATTR_OBJNVAL,
-            GRBDblAttr::OBJN => ffi::GRB_DBL_ATTR_OBJN,
-            GRBDblAttr::CDUALNORM => ffi::GRB_DBL_ATTR_CDUALNORM,
-            GRBDblAttr::QCSLACK => ffi::GRB_DBL_ATTR_QCSLACK,
-            GRBDblAttr::SLACK => ffi::GRB_DBL_ATTR_SLACK,
-            GRBDblAttr::QCPI => ffi::GRB_DBL_ATTR_QCPI,
-            GRBDblAttr::PI => ffi::GRB_DBL_ATTR_PI,
-            GRBDblAttr::VDUALNORM => ffi::GRB_DBL_ATTR_VDUALNORM,
-            GRBDblAttr::RC => ffi::GRB_DBL_ATTR_RC,
-            GRBDblAttr::BARPI => ffi::GRB_DBL_ATTR_BARPI,
-            GRBDblAttr::BARX => ffi::GRB_DBL_ATTR_BARX,
-            GRBDblAttr::XN => ffi::GRB_DBL_ATTR_XN,
-            GRBDblAttr::POOLNX => ffi::GRB_DBL_ATTR_POOLNX,
-            GRBDblAttr::X => ffi::GRB_DBL_ATTR_X,
-            GRBDblAttr::OPENNODECOUNT => ffi::GRB_DBL_ATTR_OPENNODECOUNT,
-            GRBDblAttr::NODECOUNT => ffi::GRB_DBL_ATTR_NODECOUNT,
-            GRBDblAttr::PDHGITERCOUNT => ffi::GRB_DBL_ATTR_PDHGITERCOUNT,
-            GRBDblAttr::ITERCOUNT => ffi::GRB_DBL_ATTR_ITERCOUNT,
-            GRBDblAttr::MIPGAP => ffi::GRB_DBL_ATTR_MIPGAP,
-            GRBDblAttr::POOLOBJVAL => ffi::GRB_DBL_ATTR_POOLOBJVAL,
-            GRBDblAttr::POOLNOBJVAL => ffi::GRB_DBL_ATTR_POOLNOBJVAL,
-            GRBDblAttr::POOLOBJBOUND => ffi::GRB_DBL_ATTR_POOLOBJBOUND,
-            GRBDblAttr::OBJBOUNDC => ffi::GRB_DBL_ATTR_OBJBOUNDC,
-            GRBDblAttr::OBJBOUND => ffi::GRB_DBL_ATTR_OBJBOUND,
-            GRBDblAttr::OBJVAL => ffi::GRB_DBL_ATTR_OBJVAL,
-            GRBDblAttr::WORK => ffi::GRB_DBL_ATTR_WORK,
-            GRBDblAttr::RUNTIME => ffi::GRB_DBL_ATTR_RUNTIME,
-            GRBDblAttr::MIN_QCRHS => ffi::GRB_DBL_ATTR_MIN_QCRHS,
-            GRBDblAttr::MAX_QCRHS => ffi::GRB_DBL_ATTR_MAX_QCRHS,
-            GRBDblAttr::MIN_QCLCOEFF => ffi::GRB_DBL_ATTR_MIN_QCLCOEFF,
-            GRBDblAttr::MAX_QCLCOEFF => ffi::GRB_DBL_ATTR_MAX_QCLCOEFF,
-            GRBDblAttr::MIN_QOBJ_COEFF => ffi::GRB_DBL_ATTR_MIN_QOBJ_COEFF,
-            GRBDblAttr::MAX_QOBJ_COEFF => ffi::GRB_DBL_ATTR_MAX_QOBJ_COEFF,
-            GRBDblAttr::MIN_QCCOEFF => ffi::GRB_DBL_ATTR_MIN_QCCOEFF,
-            GRBDblAttr::MAX_QCCOEFF => ffi::GRB_DBL_ATTR_MAX_QCCOEFF,
-            GRBDblAttr::MIN_RHS => ffi::GRB_DBL_ATTR_MIN_RHS,
-            GRBDblAttr::MAX_RHS => ffi::GRB_DBL_ATTR_MAX_RHS,
-            GRBDblAttr::MIN_OBJ_COEFF => ffi::GRB_DBL_ATTR_MIN_OBJ_COEFF,
-            GRBDblAttr::MAX_OBJ_COEFF => ffi::GRB_DBL_ATTR_MAX_OBJ_COEFF,
-            GRBDblAttr::MIN_BOUND => ffi::GRB_DBL_ATTR_MIN_BOUND,
-            GRBDblAttr::MAX_BOUND => ffi::GRB_DBL_ATTR_MAX_BOUND,
-            GRBDblAttr::MIN_COEFF => ffi::GRB_DBL_ATTR_MIN_COEFF,
-            GRBDblAttr::MAX_COEFF => ffi::GRB_DBL_ATTR_MAX_COEFF,
-            GRBDblAttr::FUNCPIECERATIO => ffi::GRB_DBL_ATTR_FUNCPIECERATIO,
-            GRBDblAttr::FUNCPIECELENGTH => ffi::GRB_DBL_ATTR_FUNCPIECELENGTH,
-            GRBDblAttr::FUNCPIECEERROR => ffi::GRB_DBL_ATTR_FUNCPIECEERROR,
-            GRBDblAttr::QCRHS => ffi::GRB_DBL_ATTR_QCRHS,
-            GRBDblAttr::DSTART => ffi::GRB_DBL_ATTR_DSTART,
-            GRBDblAttr::RHS => ffi::GRB_DBL_ATTR_RHS,
-            GRBDblAttr::VARHINTVAL => ffi::GRB_DBL_ATTR_VARHINTVAL,
-            GRBDblAttr::PSTART => ffi::GRB_DBL_ATTR_PSTART,
-            GRBDblAttr::START => ffi::GRB_DBL_ATTR_START,
-            GRBDblAttr::OBJ => ffi::GRB_DBL_ATTR_OBJ,
-            GRBDblAttr::UB => ffi::GRB_DBL_ATTR_UB,
-            GRBDblAttr::LB => ffi::GRB_DBL_ATTR_LB,
-            GRBDblAttr::OBJCON => ffi::GRB_DBL_ATTR_OBJCON,
-            GRBDblAttr::DNUMNZS => ffi::GRB_DBL_ATTR_DNUMNZS,
-        }
+        check_model_err(model, error)?;
+        Ok(values)
     }
 }
 
-#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
-#[derive(Clone, Copy)]
-pub enum GRBStrAttr {
-    /// name of scenario i
-    SCENNNAME,
-    /// name
-    OBJNNAME,
-    /// Name of general constraint
-    GENCONSTRNAME,
-    /// QC name
-    QCNAME,
-    /// quadratic constraint tags
-    QCTAG,
-    /// Constraint name
-    CONSTRNAME,
-    /// linear constraint tags
-    CTAG,
-    /// variable tags
-    VTAG,
-    /// Variable name
-    VARNAME,
-    /// model name
-    MODELNAME,
-}
-
-impl ConstrSetter for GRBStrAttr {
+impl<K: ConstrKind> ConstrSetter for GRBStrAttr<K> {
     type Value = String;
 
     fn set(&self, constr: &crate::constr::GRBConstr, value: Self::Value) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if constr.kind != K::OBJECT {
+            return ffi::GRB_ERROR_UNKNOWN_ATTRIBUTE;
+        }
         let value =
             CString::new(value).expect("Failed to convert String to CString in `ModelSetter::set`");
         unsafe {
@@ -779,50 +621,45 @@ impl ConstrSetter for GRBStrAttr {
     }
 }
 
-impl ConstrGetter for GRBStrAttr {
+impl<K: ConstrKind> ConstrGetter for GRBStrAttr<K> {
     type Value = String;
 
-    fn get(&self, constr: &crate::prelude::GRBConstr) -> Self::Value {
+    fn get(&self, constr: &crate::constr::GRBConstr) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        // We cannot use null_mut() here directly because we need a pointer to a pointer, the
-        // pointer we point to is allowed to be null, but the pointer itself must be valid
-        let return_ptr = (&mut null_mut()) as *mut *mut std::ffi::c_char;
+        check_object(attr_name, K::OBJECT, &[constr.kind])?;
+        let mut value = null_mut();
         let error = unsafe {
             ffi::GRBgetstrattrelement(
                 *constr.inner.0,
                 attr_name.as_ptr(),
                 constr.index() as std::ffi::c_int,
-                return_ptr,
+                &mut value,
             )
         };
-        constr.get_error(error).unwrap();
-        let c_str: &CStr = unsafe { CStr::from_ptr(*return_ptr) };
-        c_str.to_string_lossy().to_string()
+        check_model_err(*constr.inner.0, error)?;
+        Ok(string_from_ptr(value))
     }
 }
-impl VariableGetter for GRBStrAttr {
+impl VariableGetter for GRBStrAttr<kind::Var> {
     type Value = String;
 
-    fn get(&self, var: &crate::prelude::GRBVar) -> Self::Value {
+    fn get(&self, var: &crate::var::GRBVar) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        // We cannot use null_mut() here directly because we need a pointer to a pointer, the
-        // pointer we point to is allowed to be null, but the pointer itself must be valid
-        let value_p = (&mut null_mut()) as *mut *mut std::ffi::c_char;
+        let mut value = null_mut();
         let error = unsafe {
             ffi::GRBgetstrattrelement(
                 *var.inner.0,
                 attr_name.as_ptr(),
                 var.index() as std::ffi::c_int,
-                value_p,
+                &mut value,
             )
         };
-        var.get_error(error).unwrap();
-        let c_str: &CStr = unsafe { CStr::from_ptr(*value_p) };
-        c_str.to_string_lossy().to_string()
+        check_model_err(*var.inner.0, error)?;
+        Ok(string_from_ptr(value))
     }
 }
 
-impl VariableSetter for GRBStrAttr {
+impl VariableSetter for GRBStrAttr<kind::Var> {
     type Value = String;
 
     fn set(&self, var: &crate::prelude::GRBVar, value: Self::Value) -> i32 {
@@ -840,21 +677,19 @@ impl VariableSetter for GRBStrAttr {
     }
 }
 
-impl ModelGetter for GRBStrAttr {
+impl ModelGetter for GRBStrAttr<kind::Model> {
     type Value = String;
 
-    fn get(&self, model: *mut ffi::GRBmodel) -> Self::Value {
-        // We cannot use null_mut() here directly because we need a pointer to a pointer, the
-        // pointer we point to is allowed to be null, but the pointer itself must be valid
-        let value_p = (&mut null_mut()) as *mut *mut std::ffi::c_char;
+    fn get(&self, model: *mut ffi::GRBmodel) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let error = unsafe { ffi::GRBgetstrattr(model, attr_name.as_ptr(), value_p) };
-        let c_str: &CStr = unsafe { CStr::from_ptr(*value_p) };
-        c_str.to_string_lossy().to_string()
+        let mut value = null_mut();
+        let error = unsafe { ffi::GRBgetstrattr(model, attr_name.as_ptr(), &mut value) };
+        check_model_err(model, error)?;
+        Ok(string_from_ptr(value))
     }
 }
 
-impl ModelSetter for GRBStrAttr {
+impl ModelSetter for GRBStrAttr<kind::Model> {
     type Value = String;
 
     fn set(&self, model: *mut ffi::GRBmodel, value: Self::Value) -> i32 {
@@ -865,21 +700,39 @@ impl ModelSetter for GRBStrAttr {
     }
 }
 
-impl<C> ModelGetterList<C> for GRBStrAttr
+impl BatchGetter for GRBStrAttr<kind::Batch> {
+    type Value = String;
+
+    fn get(&self, batch: *mut ffi::GRBbatch) -> Result<Self::Value, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        let mut value = null_mut();
+        let error = unsafe { ffi::GRBgetbatchstrattr(batch, attr_name.as_ptr(), &mut value) };
+        check_batch_err(batch, error)?;
+        Ok(string_from_ptr(value))
+    }
+}
+
+impl<K, C> ModelGetterList<C> for GRBStrAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = String;
 
-    fn get_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>) -> Vec<Self::Value> {
+    fn get_list(
+        &self,
+        model: *mut ffi::GRBmodel,
+        inds: &[C],
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_handles(attr_name, K::OBJECT, inds)?;
         let len = inds.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
         let mut values = vec![null_mut(); len];
-        let attr_name: &CStr = (*self).into();
-        unsafe {
+        let error = unsafe {
             ffi::GRBgetstrattrlist(
                 model,
                 attr_name.as_ptr(),
@@ -888,93 +741,60 @@ where
                 values.as_mut_ptr(),
             )
         };
-        values
-            .iter()
-            .map(|&c| {
-                let c_str: &CStr = unsafe { CStr::from_ptr(c) };
-                c_str.to_string_lossy().to_string()
-            })
-            .collect::<Vec<_>>()
+        check_model_err(model, error)?;
+        Ok(values.into_iter().map(string_from_ptr).collect())
     }
 }
 
-// PERF:Cloning here might be avoidable
-// FIX: Memory leak due to CString::into_raw() - need to convert back to CString and let it drop
-impl<C> ModelSetterList<C> for GRBStrAttr
+impl<K, C> ModelSetterList<C> for GRBStrAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = String;
 
-    fn set_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>, values: Vec<Self::Value>) -> i32 {
+    fn set_list(&self, model: *mut ffi::GRBmodel, inds: &[C], values: &[Self::Value]) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if inds.len() != values.len() || check_handles(attr_name, K::OBJECT, inds).is_err() {
+            return ffi::GRB_ERROR_INVALID_ARGUMENT;
+        }
         let len = values.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
-        let mut values = values
+        let values = values
             .iter()
             .map(|s| {
-                CString::new(s.clone())
+                CString::new(s.as_str())
                     .expect("Failed to convert String to CString in `ModelSetterList::set_list`")
-                    .into_raw()
             })
             .collect::<Vec<_>>();
+        // the CStrings stay owned by `values` and are dropped after the call
+        let mut pointers = values
+            .iter()
+            .map(|s| s.as_ptr() as *mut std::ffi::c_char)
+            .collect::<Vec<_>>();
         unsafe {
             ffi::GRBsetstrattrlist(
                 model,
                 attr_name.as_ptr(),
                 len as std::ffi::c_int,
                 inds.as_mut_ptr(),
-                values.as_mut_ptr(),
+                pointers.as_mut_ptr(),
             )
         }
     }
 }
-impl From<GRBStrAttr> for &'static CStr {
-    fn from(value: GRBStrAttr) -> &'static CStr {
-        match value {
-            GRBStrAttr::SCENNNAME => ffi::GRB_STR_ATTR_SCENNNAME,
-            GRBStrAttr::OBJNNAME => ffi::GRB_STR_ATTR_OBJNNAME,
-            GRBStrAttr::GENCONSTRNAME => ffi::GRB_STR_ATTR_GENCONSTRNAME,
-            GRBStrAttr::QCNAME => ffi::GRB_STR_ATTR_QCNAME,
-            GRBStrAttr::QCTAG => ffi::GRB_STR_ATTR_QCTAG,
-            GRBStrAttr::CONSTRNAME => ffi::GRB_STR_ATTR_CONSTRNAME,
-            GRBStrAttr::CTAG => ffi::GRB_STR_ATTR_CTAG,
-            GRBStrAttr::VTAG => ffi::GRB_STR_ATTR_VTAG,
-            GRBStrAttr::VARNAME => ffi::GRB_STR_ATTR_VARNAME,
-            GRBStrAttr::MODELNAME => ffi::GRB_STR_ATTR_MODELNAME,
-        }
-    }
-}
-
-#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
-#[derive(Clone, Copy)]
-pub enum GRBCharAttr {
-    /// QC sense ('<', '>', or '=')
-    QCSENSE,
-    /// Sense ('<', '>', or '=')
-    SENSE,
-    /// Integrality type
-    VTYPE,
-}
 
-impl From<GRBCharAttr> for &'static CStr {
-    fn from(value: GRBCharAttr) -> &'static CStr {
-        match value {
-            GRBCharAttr::QCSENSE => ffi::GRB_CHAR_ATTR_QCSENSE,
-            GRBCharAttr::SENSE => ffi::GRB_CHAR_ATTR_SENSE,
-            GRBCharAttr::VTYPE => ffi::GRB_CHAR_ATTR_VTYPE,
-        }
-    }
-}
-
-impl ConstrSetter for GRBCharAttr {
+impl<K: ConstrKind> ConstrSetter for GRBCharAttr<K> {
     type Value = char;
 
     fn set(&self, constr: &crate::constr::GRBConstr, value: Self::Value) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if constr.kind != K::OBJECT {
+            return ffi::GRB_ERROR_UNKNOWN_ATTRIBUTE;
+        }
         let value = value as std::ffi::c_char;
         unsafe {
             ffi::GRBsetcharattrelement(
@@ -987,58 +807,65 @@ impl ConstrSetter for GRBCharAttr {
     }
 }
 
-impl ConstrGetter for GRBCharAttr {
+impl<K: ConstrKind> ConstrGetter for GRBCharAttr<K> {
     type Value = char;
 
-    fn get(&self, constr: &crate::prelude::GRBConstr) -> Self::Value {
+    fn get(&self, constr: &crate::constr::GRBConstr) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let return_ptr = '\0' as std::ffi::c_char;
+        check_object(attr_name, K::OBJECT, &[constr.kind])?;
+        let mut value: std::ffi::c_char = 0;
         let error = unsafe {
             ffi::GRBgetcharattrelement(
                 *constr.inner.0,
                 attr_name.as_ptr(),
                 constr.index() as std::ffi::c_int,
-                return_ptr as *mut std::ffi::c_char,
+                &mut value,
             )
         };
-        constr.get_error(error).unwrap();
-        return_ptr as u8 as char
+        check_model_err(*constr.inner.0, error)?;
+        Ok(value as u8 as char)
     }
 }
-impl VariableGetter for GRBCharAttr {
+impl VariableGetter for GRBCharAttr<kind::Var> {
     type Value = char;
 
-    fn get(&self, var: &crate::prelude::GRBVar) -> Self::Value {
+    fn get(&self, var: &crate::var::GRBVar) -> Result<Self::Value, GRBError> {
         let attr_name: &CStr = (*self).into();
-        let return_ptr = '\0' as std::ffi::c_char;
+        let mut value: std::ffi::c_char = 0;
         let error = unsafe {
             ffi::GRBgetcharattrelement(
                 *var.inner.0,
                 attr_name.as_ptr(),
                 var.index() as std::ffi::c_int,
-                return_ptr as *mut std::ffi::c_char,
+                &mut value,
             )
         };
-        var.get_error(error).unwrap();
-        return_ptr as u8 as char
+        check_model_err(*var.inner.0, error)?;
+        Ok(value as u8 as char)
     }
 }
 
-impl<C> ModelGetterList<C> for GRBCharAttr
+impl<K, C> ModelGetterList<C> for GRBCharAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = char;
 
-    fn get_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>) -> Vec<Self::Value> {
+    fn get_list(
+        &self,
+        model: *mut ffi::GRBmodel,
+        inds: &[C],
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_handles(attr_name, K::OBJECT, inds)?;
         let len = inds.len();
         let mut inds = inds
             .iter()
             .map(|c| c.index() as std::ffi::c_int)
             .collect::<Vec<_>>();
-        let mut values = vec!['\0' as std::ffi::c_char; len];
-        let attr_name: &CStr = (*self).into();
-        unsafe {
+        let mut values = vec![0 as std::ffi::c_char; len];
+        let error = unsafe {
             ffi::GRBgetcharattrlist(
                 model,
                 attr_name.as_ptr(),
@@ -1047,11 +874,12 @@ where
                 values.as_mut_ptr(),
             )
         };
-        values.iter().map(|&c| c as u8 as char).collect()
+        check_model_err(model, error)?;
+        Ok(values.iter().map(|&c| c as u8 as char).collect())
     }
 }
 
-impl VariableSetter for GRBCharAttr {
+impl VariableSetter for GRBCharAttr<kind::Var> {
     type Value = char;
 
     fn set(&self, var: &crate::prelude::GRBVar, value: Self::Value) -> i32 {
@@ -1068,14 +896,18 @@ impl VariableSetter for GRBCharAttr {
     }
 }
 
-impl<C> ModelSetterList<C> for GRBCharAttr
+impl<K, C> ModelSetterList<C> for GRBCharAttr<K>
 where
+    K: ElementKind,
     C: IsModelingObject,
 {
     type Value = char;
 
-    fn set_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>, values: Vec<Self::Value>) -> i32 {
+    fn set_list(&self, model: *mut ffi::GRBmodel, inds: &[C], values: &[Self::Value]) -> i32 {
         let attr_name: &CStr = (*self).into();
+        if inds.len() != values.len() || check_handles(attr_name, K::OBJECT, inds).is_err() {
+            return ffi::GRB_ERROR_INVALID_ARGUMENT;
+        }
         let len = values.len();
         let mut inds = inds
             .iter()
@@ -1096,4 +928,246 @@ where
         }
     }
 }
-// TODO: Add tests!
+
+/// Check that `first..first + len` is a valid range for the C API.
+fn check_range(first: usize, len: usize) -> Result<(), GRBError> {
+    if first
+        .checked_add(len)
+        .is_some_and(|end| end <= i32::MAX as usize)
+    {
+        Ok(())
+    } else {
+        Err(GRBError::new(
+            ffi::GRB_ERROR_INDEX_OUT_OF_RANGE,
+            format!("range {}..{}+{} does not fit in a c_int", first, first, len),
+        ))
+    }
+}
+
+impl<K: ElementKind> ModelGetterArray for GRBIntAttr<K> {
+    type Value = i32;
+
+    fn object(&self) -> GRBAttrObject {
+        K::OBJECT
+    }
+
+    fn get_array(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, len)?;
+        let mut values = vec![0 as std::ffi::c_int; len];
+        let error = unsafe {
+            ffi::GRBgetintattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                len as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)?;
+        Ok(values)
+    }
+}
+
+impl<K: ElementKind> ModelGetterArrayInto for GRBIntAttr<K> {
+    fn get_array_into(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        values: &mut [Self::Value],
+    ) -> Result<(), GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, values.len())?;
+        let error = unsafe {
+            ffi::GRBgetintattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                values.len() as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)
+    }
+}
+
+impl<K: ElementKind> ModelSetterArray for GRBIntAttr<K> {
+    type Value = i32;
+
+    fn set_array(&self, model: *mut ffi::GRBmodel, first: usize, values: &[Self::Value]) -> i32 {
+        let attr_name: &CStr = (*self).into();
+        if check_range(first, values.len()).is_err() {
+            return ffi::GRB_ERROR_INDEX_OUT_OF_RANGE;
+        }
+        unsafe {
+            ffi::GRBsetintattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                values.len() as std::ffi::c_int,
+                values.as_ptr() as *mut _,
+            )
+        }
+    }
+}
+
+impl<K: ElementKind> ModelGetterArray for GRBDblAttr<K> {
+    type Value = f64;
+
+    fn object(&self) -> GRBAttrObject {
+        K::OBJECT
+    }
+
+    fn get_array(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, len)?;
+        let mut values = vec![0.0 as std::ffi::c_double; len];
+        let error = unsafe {
+            ffi::GRBgetdblattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                len as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)?;
+        Ok(values)
+    }
+}
+
+impl<K: ElementKind> ModelGetterArrayInto for GRBDblAttr<K> {
+    fn get_array_into(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        values: &mut [Self::Value],
+    ) -> Result<(), GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, values.len())?;
+        let error = unsafe {
+            ffi::GRBgetdblattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                values.len() as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)
+    }
+}
+
+impl<K: ElementKind> ModelSetterArray for GRBDblAttr<K> {
+    type Value = f64;
+
+    fn set_array(&self, model: *mut ffi::GRBmodel, first: usize, values: &[Self::Value]) -> i32 {
+        let attr_name: &CStr = (*self).into();
+        if check_range(first, values.len()).is_err() {
+            return ffi::GRB_ERROR_INDEX_OUT_OF_RANGE;
+        }
+        unsafe {
+            ffi::GRBsetdblattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                values.len() as std::ffi::c_int,
+                values.as_ptr() as *mut _,
+            )
+        }
+    }
+}
+
+impl<K: ElementKind> ModelGetterArray for GRBCharAttr<K> {
+    type Value = char;
+
+    fn object(&self) -> GRBAttrObject {
+        K::OBJECT
+    }
+
+    fn get_array(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, len)?;
+        let mut values = vec![0 as std::ffi::c_char; len];
+        let error = unsafe {
+            ffi::GRBgetcharattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                len as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)?;
+        Ok(values.iter().map(|&c| c as u8 as char).collect())
+    }
+}
+
+impl<K: ElementKind> ModelSetterArray for GRBCharAttr<K> {
+    type Value = char;
+
+    fn set_array(&self, model: *mut ffi::GRBmodel, first: usize, values: &[Self::Value]) -> i32 {
+        let attr_name: &CStr = (*self).into();
+        if check_range(first, values.len()).is_err() {
+            return ffi::GRB_ERROR_INDEX_OUT_OF_RANGE;
+        }
+        let values = values
+            .iter()
+            .map(|c| *c as std::ffi::c_char)
+            .collect::<Vec<_>>();
+        unsafe {
+            ffi::GRBsetcharattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                values.len() as std::ffi::c_int,
+                values.as_ptr() as *mut _,
+            )
+        }
+    }
+}
+
+impl<K: ElementKind> ModelGetterArray for GRBStrAttr<K> {
+    type Value = String;
+
+    fn object(&self) -> GRBAttrObject {
+        K::OBJECT
+    }
+
+    fn get_array(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<Self::Value>, GRBError> {
+        let attr_name: &CStr = (*self).into();
+        check_range(first, len)?;
+        let mut values = vec![null_mut(); len];
+        let error = unsafe {
+            ffi::GRBgetstrattrarray(
+                model,
+                attr_name.as_ptr(),
+                first as std::ffi::c_int,
+                len as std::ffi::c_int,
+                values.as_mut_ptr(),
+            )
+        };
+        check_model_err(model, error)?;
+        Ok(values.into_iter().map(string_from_ptr).collect())
+    }
+}
@@ -0,0 +1,122 @@
+// Simplex bases: the status of every variable (`VBasis`) and linear constraint (`CBasis`), used
+// to warm start an LP after it was modified.
+
+use crate::{
+    attributes::{GRBAttrObject, GRBIntAttr},
+    constr::GRBConstr,
+    error::{check_model_err, GRBError},
+    ffi,
+    model::{GRBModel, ModelSetterArray},
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+/// Status of a variable or constraint (its slack) in a simplex basis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BasisStatus {
+    Basic,
+    /// Nonbasic at its lower bound
+    NonbasicLower,
+    /// Nonbasic at its upper bound
+    NonbasicUpper,
+    /// Nonbasic and not at a bound
+    SuperBasic,
+}
+
+impl From<BasisStatus> for i32 {
+    fn from(value: BasisStatus) -> Self {
+        match value {
+            BasisStatus::Basic => ffi::GRB_BASIC,
+            BasisStatus::NonbasicLower => ffi::GRB_NONBASIC_LOWER,
+            BasisStatus::NonbasicUpper => ffi::GRB_NONBASIC_UPPER,
+            BasisStatus::SuperBasic => ffi::GRB_SUPERBASIC,
+        }
+    }
+}
+
+impl TryFrom<i32> for BasisStatus {
+    type Error = GRBError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            ffi::GRB_BASIC => Ok(BasisStatus::Basic),
+            ffi::GRB_NONBASIC_LOWER => Ok(BasisStatus::NonbasicLower),
+            ffi::GRB_NONBASIC_UPPER => Ok(BasisStatus::NonbasicUpper),
+            ffi::GRB_SUPERBASIC => Ok(BasisStatus::SuperBasic),
+            _ => Err(GRBError::new(
+                ffi::GRB_ERROR_VALUE_OUT_OF_RANGE,
+                format!("{} is not a valid basis status", value),
+            )),
+        }
+    }
+}
+
+/// A simplex basis of a model, read with `GRBModel::basis` and loaded with
+/// `GRBModel::set_basis`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Basis {
+    pub vars: Vec<BasisStatus>,
+    pub constrs: Vec<BasisStatus>,
+}
+
+impl Basis {
+    /// The status of a variable, `None` if the basis has no variable with its index, e.g. for a
+    /// variable added after the basis was read.
+    pub fn var(&self, var: &GRBVar) -> Option<BasisStatus> {
+        self.vars.get(var.index()).copied()
+    }
+
+    /// The status of a linear constraint, `None` for quadratic and general constraints, which are
+    /// not part of the basis.
+    pub fn constr(&self, constr: &GRBConstr) -> Option<BasisStatus> {
+        if constr.kind() != GRBAttrObject::Constr {
+            return None;
+        }
+        self.constrs.get(constr.index()).copied()
+    }
+}
+
+fn statuses(values: Vec<i32>) -> Result<Vec<BasisStatus>, GRBError> {
+    values.into_iter().map(BasisStatus::try_from).collect()
+}
+
+impl GRBModel {
+    /// The current basis, available after the LP was solved with simplex (or barrier with
+    /// crossover).
+    pub fn basis(&self) -> Result<Basis, GRBError> {
+        Ok(Basis {
+            vars: statuses(self.get_all(GRBIntAttr::VBASIS)?)?,
+            constrs: statuses(self.get_all(GRBIntAttr::CBASIS)?)?,
+        })
+    }
+
+    /// Load `basis` as the starting basis of the next simplex solve.
+    ///
+    /// A basis can also be read from and written to a `.bas` file with `model.read` and
+    /// `model.write`.
+    pub fn set_basis(&mut self, basis: &Basis) -> Result<(), GRBError> {
+        self.update()?;
+        let num_vars = self.get(GRBIntAttr::NUMVARS)? as usize;
+        let num_constrs = self.get(GRBIntAttr::NUMCONSTRS)? as usize;
+        if basis.vars.len() != num_vars || basis.constrs.len() != num_constrs {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!(
+                    "basis has {} variables and {} constraints, the model has {} and {}",
+                    basis.vars.len(),
+                    basis.constrs.len(),
+                    num_vars,
+                    num_constrs
+                ),
+            ));
+        }
+        let vbasis: Vec<i32> = basis.vars.iter().map(|s| (*s).into()).collect();
+        let cbasis: Vec<i32> = basis.constrs.iter().map(|s| (*s).into()).collect();
+        let error = GRBIntAttr::VBASIS.set_array(*self.inner.0, 0, &vbasis);
+        check_model_err(*self.inner.0, error)?;
+        let error = GRBIntAttr::CBASIS.set_array(*self.inner.0, 0, &cbasis);
+        check_model_err(*self.inner.0, error)
+    }
+}
@@ -0,0 +1,118 @@
+// Batch optimization on a Cluster Manager with
+// [GRBoptimizebatch](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/batch.html#c.GRBoptimizebatch)
+// and the `GRBbatch` object that reports on a submitted batch.
+//
+// A batch is submitted from a model whose environment connects to a Cluster Manager with
+// `CSBatchMode` set. The `Batch` attributes (e.g. `GRBIntAttr::BATCHSTATUS`) are only readable
+// through a `GRBbatch`.
+
+use std::ffi::CStr;
+use std::ptr::null_mut;
+use std::rc::Rc;
+
+use crate::{
+    env::{get_env_error, to_cstring, GRBenv, GRBenvPtr},
+    error::{check_model_err, GRBError},
+    ffi,
+    model::GRBModel,
+};
+
+pub trait BatchGetter {
+    type Value;
+    fn get(&self, batch: *mut ffi::GRBbatch) -> Result<Self::Value, GRBError>;
+}
+
+/// Turn the error code of a call on `batch` into a `GRBError` with the message of its environment.
+pub(crate) fn check_batch_err(batch: *mut ffi::GRBbatch, error_code: i32) -> Result<(), GRBError> {
+    get_env_error(unsafe { ffi::GRBgetbatchenv(batch) }, error_code)
+}
+
+/// A batch submitted to a Cluster Manager. The attributes are a snapshot, `update` refreshes them.
+pub struct GRBbatch {
+    inner: *mut ffi::GRBbatch,
+    // the batch is freed before the environment it was retrieved with
+    _env: Rc<GRBenvPtr>,
+}
+
+impl Drop for GRBbatch {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::GRBfreebatch(self.inner);
+        }
+    }
+}
+
+impl GRBbatch {
+    /// Retrieve the batch with ID `batch_id`, e.g. as returned by `GRBModel::optimize_batch`.
+    pub fn new(env: &GRBenv, batch_id: &str) -> Result<GRBbatch, GRBError> {
+        let batch_id = to_cstring(batch_id, "batch ID")?;
+        let mut batch = null_mut();
+        let error = unsafe { ffi::GRBgetbatch(env.inner(), batch_id.as_ptr(), &mut batch) };
+        get_env_error(env.inner(), error)?;
+        Ok(GRBbatch {
+            inner: batch,
+            _env: env.handle(),
+        })
+    }
+
+    /// Get an attribute of the batch, e.g. `GRBIntAttr::BATCHSTATUS`.
+    pub fn get<G: BatchGetter>(&self, what: G) -> Result<G::Value, GRBError> {
+        what.get(self.inner)
+    }
+
+    /// Query the Cluster Manager for the current state of the batch.
+    pub fn update(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBupdatebatch(self.inner) };
+        check_batch_err(self.inner, error)
+    }
+
+    /// Abort the batch, if it is not completed yet.
+    pub fn abort(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBabortbatch(self.inner) };
+        check_batch_err(self.inner, error)
+    }
+
+    /// Remove the batch and its data from the Cluster Manager.
+    pub fn discard(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBdiscardbatch(self.inner) };
+        check_batch_err(self.inner, error)
+    }
+
+    /// Submit a failed or aborted batch again.
+    pub fn retry(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBretrybatch(self.inner) };
+        check_batch_err(self.inner, error)
+    }
+
+    /// The solution of a completed batch in the JSON solution format.
+    pub fn json_solution(&self) -> Result<String, GRBError> {
+        let mut solution = null_mut();
+        let error = unsafe { ffi::GRBgetbatchjsonsolution(self.inner, &mut solution) };
+        check_batch_err(self.inner, error)?;
+        Ok(unsafe { CStr::from_ptr(solution) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Write the solution of a completed batch to a JSON solution file, e.g. `solution.json.gz`.
+    pub fn write_json_solution(&self, filename: &str) -> Result<(), GRBError> {
+        let filename = to_cstring(filename, "filename")?;
+        let error = unsafe { ffi::GRBwritebatchjsonsolution(self.inner, filename.as_ptr()) };
+        check_batch_err(self.inner, error)
+    }
+}
+
+impl GRBModel {
+    /// Submit the model as a batch to the Cluster Manager and return the ID of the batch, to be
+    /// retrieved with `GRBbatch::new`. Requires `CSBatchMode` and a Cluster Manager in the
+    /// environment of the model.
+    pub fn optimize_batch(&mut self) -> Result<String, GRBError> {
+        self.update()?;
+        let mut batch_id = vec![0 as std::ffi::c_char; ffi::GRB_MAX_STRLEN as usize + 1];
+        let error = unsafe { ffi::GRBoptimizebatch(*self.inner.0, batch_id.as_mut_ptr()) };
+        check_model_err(*self.inner.0, error)?;
+        Ok(unsafe { CStr::from_ptr(batch_id.as_ptr()) }
+            .to_string_lossy()
+            .into_owned())
+    }
+}
@@ -53,16 +53,45 @@ unsafe extern "C" fn c_shim<C: CallbackTrait>(
     }
 }
 
+/// A callback as registered with gurobi: the shim and the `GRBCallback` it is called with. Kept
+/// on the model so the optimize variants that install their own callback can restore it.
+pub(crate) type RawCallback = (
+    unsafe extern "C" fn(
+        *mut ffi::GRBmodel,
+        *mut std::ffi::c_void,
+        std::ffi::c_int,
+        *mut std::ffi::c_void,
+    ) -> i32,
+    *mut std::ffi::c_void,
+);
+
 impl GRBModel {
     pub fn set_callback<C: CallbackTrait>(&mut self, callback: &mut GRBCallback<C>) {
+        self.register_callback(Some((
+            c_shim::<C>,
+            callback as *mut _ as *mut std::ffi::c_void,
+        )));
+    }
+
+    /// Unregister the callback set with `set_callback`.
+    pub fn clear_callback(&mut self) {
+        self.register_callback(None);
+    }
+
+    /// Register `callback` (or none) with gurobi and return the callback it replaces.
+    pub(crate) fn register_callback(
+        &mut self,
+        callback: Option<RawCallback>,
+    ) -> Option<RawCallback> {
+        let (shim, user_data) = match callback {
+            Some((shim, user_data)) => (Some(shim), user_data),
+            None => (None, std::ptr::null_mut()),
+        };
         unsafe {
             // PERF: Check if [GRBsetcallbackfuncadv](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/logging.html#c.GRBsetcallbackfuncadv) could lead to performance improvements in certain scenarios.
-            ffi::GRBsetcallbackfunc(
-                *self.inner.0,
-                Some(c_shim::<C>),
-                callback as *mut _ as *mut std::ffi::c_void,
-            );
+            ffi::GRBsetcallbackfunc(*self.inner.0, shim, user_data);
         }
+        std::mem::replace(&mut self.callback, callback)
     }
 }
 
@@ -8,7 +8,8 @@ use std::{
 };
 
 use crate::{
-    error::check_err,
+    attributes::GRBAttrObject,
+    error::{check_err, GRBError},
     ffi,
     model::GRBModelPtr,
     modeling::{
@@ -20,7 +21,7 @@ use crate::{
 
 pub trait ConstrGetter {
     type Value;
-    fn get(&self, constr: &GRBConstr) -> Self::Value;
+    fn get(&self, constr: &GRBConstr) -> Result<Self::Value, GRBError>;
 }
 
 pub trait ConstrSetter {
@@ -288,13 +289,22 @@ impl GRBConstr {
         self.get_error(err_code).unwrap();
     }
 
-    pub fn get<G: ConstrGetter>(&self, getter: G) -> G::Value {
+    pub fn get<G: ConstrGetter>(&self, getter: G) -> Result<G::Value, GRBError> {
         getter.get(self)
     }
+
+    /// The kind of constraint: `Constr` (linear), `QConstr` or `GenConstr`.
+    pub fn kind(&self) -> GRBAttrObject {
+        self.kind
+    }
 }
 
+/// Handle of a linear, quadratic or general constraint. `index` counts the constraints of the same
+/// kind, e.g. the first quadratic constraint has index 0 even if linear constraints were added
+/// before it.
 pub struct GRBConstr {
     pub index: usize,
+    pub(crate) kind: GRBAttrObject,
     pub(crate) inner: GRBModelPtr,
 }
 
@@ -302,6 +312,14 @@ impl IsModelingObject for GRBConstr {
     fn index(&self) -> usize {
         self.index
     }
+
+    fn object(&self) -> GRBAttrObject {
+        self.kind
+    }
+
+    fn model_ptr(&self) -> &GRBModelPtr {
+        &self.inner
+    }
 }
 
 impl AddAsIndicator for TempConstr {
@@ -0,0 +1,211 @@
+// Models derived from an existing model: the continuous relaxation, the fixed model after a MIP
+// solve, the presolved model and a plain copy. Each is an independent `GRBModel` with its own
+// copy of the environment.
+
+use std::{
+    collections::HashMap,
+    ptr::null_mut,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    attributes::{GRBAttrObject, GRBIntAttr, GRBStrAttr},
+    constr::GRBConstr,
+    error::{check_model_err, GRBError},
+    ffi,
+    model::GRBModel,
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+/// A model derived from another model with `relax`, `fixed`, `presolve` or `copy`, together with
+/// the mapping from handles of the original model to handles of the derived model.
+pub struct DerivedModel {
+    pub model: GRBModel,
+    // the model this one was derived from, only to recognize its handles
+    original: Weak<*mut ffi::GRBmodel>,
+    // index in the derived model of every variable/constraint of the original model, per kind
+    vars: Vec<Option<usize>>,
+    constrs: Vec<Option<usize>>,
+    qconstrs: Vec<Option<usize>>,
+    genconstrs: Vec<Option<usize>>,
+}
+
+impl DerivedModel {
+    /// The handle in the derived model of `var` from the original model, `None` if the variable
+    /// was removed or `var` is not a variable of the original model.
+    pub fn var(&self, var: &GRBVar) -> Option<GRBVar> {
+        if !self.is_original(&var.inner.0) {
+            return None;
+        }
+        self.vars
+            .get(var.index())
+            .copied()
+            .flatten()
+            .map(|index| GRBVar::new(index, self.model.inner(), None))
+    }
+
+    /// The handle in the derived model of `constr` from the original model, of the same kind,
+    /// `None` if the constraint was removed (presolve, or general constraints in the relaxation)
+    /// or `constr` is not a constraint of the original model.
+    pub fn constr(&self, constr: &GRBConstr) -> Option<GRBConstr> {
+        if !self.is_original(&constr.inner.0) {
+            return None;
+        }
+        let mapping = match constr.kind {
+            GRBAttrObject::Constr => &self.constrs,
+            GRBAttrObject::QConstr => &self.qconstrs,
+            GRBAttrObject::GenConstr => &self.genconstrs,
+            _ => return None,
+        };
+        mapping
+            .get(constr.index())
+            .copied()
+            .flatten()
+            .map(|index| GRBConstr {
+                index,
+                kind: constr.kind,
+                inner: self.model.inner(),
+            })
+    }
+
+    pub fn into_model(self) -> GRBModel {
+        self.model
+    }
+
+    fn is_original(&self, inner: &Rc<*mut ffi::GRBmodel>) -> bool {
+        Weak::ptr_eq(&self.original, &Rc::downgrade(inner))
+    }
+}
+
+// index of every name that occurs once in `names`, `None` for duplicated names
+fn unique_names(names: &[String]) -> HashMap<&str, Option<usize>> {
+    let mut index = HashMap::new();
+    for (i, name) in names.iter().enumerate() {
+        index
+            .entry(name.as_str())
+            .and_modify(|found| *found = None)
+            .or_insert(Some(i));
+    }
+    index
+}
+
+// map every name that is unique in both `original` and `derived` to its index in `derived`,
+// duplicated names are left unmapped as there is no telling which is which
+fn map_by_name(original: Vec<String>, derived: Vec<String>) -> Vec<Option<usize>> {
+    let in_original = unique_names(&original);
+    let in_derived = unique_names(&derived);
+    original
+        .iter()
+        .map(|name| {
+            in_original[name.as_str()]?;
+            in_derived.get(name.as_str()).copied().flatten()
+        })
+        .collect()
+}
+
+// map by position when the derived model kept every row/column of the original in order, which
+// is checked by comparing the names, otherwise fall back to the names
+fn map_by_position(original: Vec<String>, derived: Vec<String>) -> Vec<Option<usize>> {
+    if original == derived {
+        (0..original.len()).map(Some).collect()
+    } else {
+        map_by_name(original, derived)
+    }
+}
+
+impl GRBModel {
+    /// The continuous relaxation of this model, integrality restrictions are dropped and SOS and
+    /// general constraints are relaxed.
+    pub fn relax(&mut self) -> Result<DerivedModel, GRBError> {
+        self.derive(
+            |model, derived| unsafe { ffi::GRBrelaxmodel(model, derived) },
+            false,
+        )
+    }
+
+    /// The fixed model of a MIP with a solution: integer variables are fixed to their values in
+    /// the solution, so the model is continuous and has dual values.
+    pub fn fixed(&mut self) -> Result<DerivedModel, GRBError> {
+        self.derive(
+            |model, derived| unsafe { ffi::GRBfixmodel(model, derived) },
+            false,
+        )
+    }
+
+    /// The presolved model. Presolve removes variables and constraints, the mapping is made by
+    /// name, so only variables and constraints with unique names are mapped.
+    pub fn presolve(&mut self) -> Result<DerivedModel, GRBError> {
+        self.derive(
+            |model, derived| unsafe { ffi::GRBpresolvemodel(model, derived) },
+            true,
+        )
+    }
+
+    /// An independent copy of this model.
+    pub fn copy(&mut self) -> Result<DerivedModel, GRBError> {
+        self.derive(
+            |model, derived| unsafe {
+                *derived = ffi::GRBcopymodel(model);
+                if derived.is_null() {
+                    ffi::GRB_ERROR_OUT_OF_MEMORY
+                } else {
+                    0
+                }
+            },
+            false,
+        )
+    }
+
+    fn derive<F>(&mut self, create: F, by_name: bool) -> Result<DerivedModel, GRBError>
+    where
+        F: FnOnce(*mut ffi::GRBmodel, *mut *mut ffi::GRBmodel) -> i32,
+    {
+        self.update()?;
+        let mut derived = null_mut();
+        let error = create(*self.inner.0, &mut derived);
+        check_model_err(*self.inner.0, error)?;
+        if derived.is_null() {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_DATA_NOT_AVAILABLE,
+                "no derived model was created",
+            ));
+        }
+        // owns `derived` from here on, so it is freed on errors below
+        let mut model = GRBModel::from_raw(derived, self.inner.1.clone());
+        model.var_index = model.get(GRBIntAttr::NUMVARS)? as usize;
+        model.cons_index = model.get(GRBIntAttr::NUMCONSTRS)? as usize;
+        model.qcons_index = model.get(GRBIntAttr::NUMQCONSTRS)? as usize;
+        model.gencons_index = model.get(GRBIntAttr::NUMGENCONSTRS)? as usize;
+
+        let map = if by_name {
+            map_by_name
+        } else {
+            map_by_position
+        };
+        let vars = map(
+            self.get_all(GRBStrAttr::VARNAME)?,
+            model.get_all(GRBStrAttr::VARNAME)?,
+        );
+        let constrs = map(
+            self.get_all(GRBStrAttr::CONSTRNAME)?,
+            model.get_all(GRBStrAttr::CONSTRNAME)?,
+        );
+        let qconstrs = map(
+            self.get_all(GRBStrAttr::QCNAME)?,
+            model.get_all(GRBStrAttr::QCNAME)?,
+        );
+        let genconstrs = map(
+            self.get_all(GRBStrAttr::GENCONSTRNAME)?,
+            model.get_all(GRBStrAttr::GENCONSTRNAME)?,
+        );
+        Ok(DerivedModel {
+            model,
+            original: Rc::downgrade(&self.inner.0),
+            vars,
+            constrs,
+            qconstrs,
+            genconstrs,
+        })
+    }
+}
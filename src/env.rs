@@ -1,76 +1,319 @@
 use std::{
     ffi::{c_char, CStr, CString},
+    marker::PhantomData,
     ptr::{null, null_mut},
+    rc::Rc,
 };
 
-use crate::{error::check_err, ffi};
+use crate::{
+    error::{check_err, GRBError},
+    ffi,
+    model::{EnvGetter, EnvSetter},
+    param_set::{ParamSet, ParamSetKey},
+    parameters::{GRBIntParam, GRBStrParam, ParamInfo},
+};
+
+/// Owner of the environment pointer, freed when the `GRBenv` and every model created from it
+/// are dropped.
+pub(crate) struct GRBenvPtr(*mut ffi::GRBenv);
+
+impl Drop for GRBenvPtr {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::GRBfreeenv(self.0);
+        }
+    }
+}
 
 pub struct GRBenv {
-    inner: *mut ffi::GRBenv,
+    inner: Rc<GRBenvPtr>,
 }
 
 impl GRBenv {
     pub fn inner(&self) -> *mut ffi::GRBenv {
-        self.inner
+        self.inner.0
+    }
+
+    // shared handle that keeps the environment alive for the models created from it
+    pub(crate) fn handle(&self) -> Rc<GRBenvPtr> {
+        self.inner.clone()
     }
 
-    pub fn new(empty: bool, logfilename: Option<&str>) -> Result<GRBenv, String> {
+    pub fn new(empty: bool, logfilename: Option<&str>) -> Result<GRBenv, GRBError> {
         // Create the GRBenv pointer
         let mut env_ptr = null_mut();
         // Prepare the logfilename pointer
-        let logfilename_ptr = match logfilename.map(|s| CString::new(s)) {
-            Some(Ok(cstr)) => cstr.as_ptr(),
-            Some(Err(_)) => return Err("Failed to convert logfilename to CString".to_string()),
-            None => null(),
-        };
+        let logfilename = logfilename
+            .map(|name| to_cstring(name, "logfilename"))
+            .transpose()?;
+        // the CString has to outlive the call
+        let logfilename_ptr = logfilename.as_ref().map_or(null(), |cstr| cstr.as_ptr());
         // Call the appropriate FFI function
         let error = if empty {
             unsafe { ffi::GRBemptyenv(&mut env_ptr) }
         } else {
             unsafe { ffi::GRBloadenv(&mut env_ptr, logfilename_ptr) }
         };
-        let env = GRBenv { inner: env_ptr };
-        env.get_error(error).unwrap();
+        let env = GRBenv {
+            inner: Rc::new(GRBenvPtr(env_ptr)),
+        };
+        env.get_error(error)?;
         Ok(env)
     }
 
-    pub fn start(&mut self) -> () {
-        unsafe {
-            ffi::GRBstartenv(self.inner);
+    /// Configure an empty environment before starting it, e.g. to set license related
+    /// parameters:
+    ///
+    /// ```ignore
+    /// let env = GRBenv::builder()
+    ///     .output_flag(false)
+    ///     .param(GRBIntParam::THREADS, 4)
+    ///     .token_server("license.example.com")
+    ///     .start()?;
+    /// ```
+    pub fn builder() -> GRBenvBuilder {
+        GRBenvBuilder {
+            params: ParamSet::new(),
         }
     }
 
-    pub fn get_error(&self, error_code: i32) -> Result<(), String> {
-        match check_err(error_code) {
-            Err(e) => unsafe {
-                Err(format!(
-                    "ERROR CODE {}: {}",
-                    e,
-                    CStr::from_ptr(ffi::GRBgeterrormsg(self.inner()) as *mut c_char)
-                        .to_string_lossy()
-                ))
-            },
-            Ok(_o) => Ok(()),
+    /// Create and start an environment with the parameter settings of a `.prm` file, read with
+    /// [GRBreadparams](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/parameters.html#c.GRBreadparams).
+    pub fn from_prm_file(filename: &str) -> Result<GRBenv, GRBError> {
+        let mut env = GRBenv::new(true, None)?;
+        env.read_params(filename)?;
+        env.start()?;
+        Ok(env)
+    }
+
+    /// View on the parameters of this environment. Models created afterwards copy the values.
+    pub fn params(&mut self) -> GRBParams<'_> {
+        GRBParams::new(self.inner(), ParamScope::Env)
+    }
+
+    /// Set a parameter of this environment. Models created afterwards copy the value.
+    pub fn set<S: EnvSetter>(&mut self, what: S, value: S::Value) -> Result<(), GRBError> {
+        let error = what.set(self.inner(), value);
+        get_env_error(self.inner(), error)
+    }
+
+    /// Set a parameter by name from its value as a string, e.g.
+    /// `env.set_param_str("TimeLimit", "60")`. The name is not case sensitive.
+    pub fn set_param_str(&mut self, name: &str, value: &str) -> Result<(), GRBError> {
+        set_param_str(self.inner(), name, value)
+    }
+
+    /// Get the value of a parameter of this environment.
+    pub fn get<G: EnvGetter>(&self, what: G) -> Result<G::Value, GRBError> {
+        what.get(self.inner())
+    }
+
+    /// Get the current, minimum, maximum and default value of a parameter.
+    pub fn param_info<G: EnvGetter>(&self, what: G) -> Result<ParamInfo<G::Value>, GRBError> {
+        what.info(self.inner())
+    }
+
+    /// Reset all parameters of this environment to their default values.
+    pub fn reset_params(&mut self) -> Result<(), GRBError> {
+        reset_params(self.inner())
+    }
+
+    /// Read parameter settings from a `.prm` file.
+    pub fn read_params(&mut self, filename: &str) -> Result<(), GRBError> {
+        read_params(self.inner(), filename)
+    }
+
+    /// Write the non-default parameter settings of this environment to a `.prm` file.
+    pub fn write_params(&self, filename: &str) -> Result<(), GRBError> {
+        write_params(self.inner(), filename)
+    }
+
+    /// The error message of the most recent error in this environment.
+    pub fn error_message(&self) -> String {
+        unsafe {
+            CStr::from_ptr(ffi::GRBgeterrormsg(self.inner()))
+                .to_string_lossy()
+                .into_owned()
         }
     }
+
+    /// Start an environment created with `GRBenv::new(true, ..)`, after its parameters are set.
+    pub fn start(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBstartenv(self.inner()) };
+        self.get_error(error)
+    }
+
+    pub fn get_error(&self, error_code: i32) -> Result<(), GRBError> {
+        get_env_error(self.inner(), error_code)
+    }
 }
 
-impl Default for GRBenv {
-    fn default() -> Self {
-        GRBenv::new(false, None).expect("Failed to create default GRBenv")
+/// Builder for an environment that is configured before it is started, created with
+/// `GRBenv::builder`.
+pub struct GRBenvBuilder {
+    params: ParamSet,
+}
+
+impl GRBenvBuilder {
+    /// Write the log to `filename`.
+    pub fn log_file(mut self, filename: &str) -> Self {
+        self.params.set_str(GRBStrParam::LOGFILE, filename);
+        self
+    }
+
+    /// Enable or disable all output, including the license banner when the env is started.
+    pub fn output_flag(mut self, output: bool) -> Self {
+        self.params.set_int(GRBIntParam::OUTPUTFLAG, output as i32);
+        self
+    }
+
+    /// Set any parameter before the environment is started.
+    pub fn param<P: ParamSetKey>(mut self, what: P, value: P::Value) -> Self {
+        self.params.set(what, value);
+        self
+    }
+
+    /// Set all parameters of `params` before the environment is started.
+    pub fn params(mut self, params: &ParamSet) -> Self {
+        self.params.extend(params);
+        self
+    }
+
+    /// Use the token server at `server` for the license.
+    pub fn token_server(mut self, server: &str) -> Self {
+        self.params.set_str(GRBStrParam::TOKENSERVER, server);
+        self
+    }
+
+    /// Solve on the Compute Server at `server`.
+    pub fn compute_server(mut self, server: &str) -> Self {
+        self.params.set_str(GRBStrParam::COMPUTESERVER, server);
+        self
+    }
+
+    /// Create the empty environment, apply the parameters and start it.
+    pub fn start(self) -> Result<GRBenv, GRBError> {
+        let mut env = GRBenv::new(true, None)?;
+        self.params.apply_to_env(&mut env)?;
+        env.start()?;
+        Ok(env)
     }
 }
 
-impl Drop for GRBenv {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::GRBfreeenv(self.inner);
+/// Which environment a `GRBParams` view reads and writes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamScope {
+    /// A `GRBenv`, changes are copied into models created from it afterwards.
+    Env,
+    /// The copy of the environment owned by a single model, changes only affect that model.
+    Model,
+}
+
+/// View on the parameters of a `GRBenv` (`GRBenv::params`) or of a model (`GRBModel::params`).
+pub struct GRBParams<'a> {
+    env: *mut ffi::GRBenv,
+    scope: ParamScope,
+    _owner: PhantomData<&'a mut ()>,
+}
+
+impl GRBParams<'_> {
+    pub(crate) fn new(env: *mut ffi::GRBenv, scope: ParamScope) -> Self {
+        Self {
+            env,
+            scope,
+            _owner: PhantomData,
         }
     }
+
+    pub fn scope(&self) -> ParamScope {
+        self.scope
+    }
+
+    pub fn get<G: EnvGetter>(&self, what: G) -> Result<G::Value, GRBError> {
+        what.get(self.env)
+    }
+
+    pub fn set<S: EnvSetter>(&mut self, what: S, value: S::Value) -> Result<(), GRBError> {
+        let error = what.set(self.env, value);
+        get_env_error(self.env, error)
+    }
+
+    pub fn info<G: EnvGetter>(&self, what: G) -> Result<ParamInfo<G::Value>, GRBError> {
+        what.info(self.env)
+    }
+
+    /// Set a parameter by name from its value as a string, see `GRBenv::set_param_str`.
+    pub fn set_param_str(&mut self, name: &str, value: &str) -> Result<(), GRBError> {
+        set_param_str(self.env, name, value)
+    }
+
+    /// Reset all parameters in this scope to their default values.
+    pub fn reset(&mut self) -> Result<(), GRBError> {
+        reset_params(self.env)
+    }
+
+    /// Read parameter settings from a `.prm` file.
+    pub fn read(&mut self, filename: &str) -> Result<(), GRBError> {
+        read_params(self.env, filename)
+    }
+
+    /// Write the non-default parameter settings to a `.prm` file.
+    pub fn write(&self, filename: &str) -> Result<(), GRBError> {
+        write_params(self.env, filename)
+    }
+}
+
+// The functions below work on a raw environment pointer, so they can be used both on a `GRBenv`
+// and on the copy of the environment that every model owns (`GRBgetenv`).
+
+pub(crate) fn get_env_error(env: *mut ffi::GRBenv, error_code: i32) -> Result<(), GRBError> {
+    match check_err(error_code) {
+        Err(e) => Err(GRBError::new(e, unsafe {
+            CStr::from_ptr(ffi::GRBgeterrormsg(env) as *mut c_char).to_string_lossy()
+        })),
+        Ok(_o) => Ok(()),
+    }
+}
+
+pub(crate) fn to_cstring(text: &str, what: &str) -> Result<CString, GRBError> {
+    CString::new(text).map_err(|_| {
+        GRBError::new(
+            ffi::GRB_ERROR_INVALID_ARGUMENT,
+            format!("Failed to convert {} to CString", what),
+        )
+    })
+}
+
+pub(crate) fn set_param_str(
+    env: *mut ffi::GRBenv,
+    name: &str,
+    value: &str,
+) -> Result<(), GRBError> {
+    let name = to_cstring(name, "name")?;
+    let value = to_cstring(value, "value")?;
+    let error = unsafe { ffi::GRBsetparam(env, name.as_ptr(), value.as_ptr()) };
+    get_env_error(env, error)
+}
+
+pub(crate) fn reset_params(env: *mut ffi::GRBenv) -> Result<(), GRBError> {
+    let error = unsafe { ffi::GRBresetparams(env) };
+    get_env_error(env, error)
+}
+
+pub(crate) fn read_params(env: *mut ffi::GRBenv, filename: &str) -> Result<(), GRBError> {
+    let filename = to_cstring(filename, "filename")?;
+    let error = unsafe { ffi::GRBreadparams(env, filename.as_ptr()) };
+    get_env_error(env, error)
+}
+
+pub(crate) fn write_params(env: *mut ffi::GRBenv, filename: &str) -> Result<(), GRBError> {
+    let filename = to_cstring(filename, "filename")?;
+    let error = unsafe { ffi::GRBwriteparams(env, filename.as_ptr()) };
+    get_env_error(env, error)
 }
 
-// TODO: Implement;
-// [get](https://docs.gurobi.com/projects/optimizer/en/current/reference/cpp/env.html#_CPPv4N6GRBEnv3getE15GRB_DoubleParam)
-// [getErrorMsg](https://docs.gurobi.com/projects/optimizer/en/current/reference/cpp/env.html#_CPPv4N6GRBEnv11getErrorMsgEv)
-// [getParamInfo](https://docs.gurobi.com/projects/optimizer/en/current/reference/cpp/env.html#_CPPv4N6GRBEnv12getParamInfoE15GRB_StringParamP6stringP6string)
-// also set, readParams, resetParams, writeParams etc.
+impl Default for GRBenv {
+    fn default() -> Self {
+        GRBenv::new(false, None).expect("Failed to create default GRBenv")
+    }
+}
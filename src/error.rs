@@ -1,3 +1,5 @@
+use std::{error::Error, ffi::CStr, fmt};
+
 use crate::ffi;
 
 pub(crate) fn check_err(error_code: i32) -> Result<i32, i32> {
@@ -39,3 +41,45 @@ pub(crate) fn check_err(error_code: i32) -> Result<i32, i32> {
         _ => Ok(error_code),
     }
 }
+
+/// An error reported by Gurobi, or by this crate for invalid input (with one of the Gurobi error
+/// codes, e.g. `GRB_ERROR_INVALID_ARGUMENT`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GRBError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl GRBError {
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for GRBError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ERROR CODE {}: {}", self.code, self.message)
+    }
+}
+
+impl Error for GRBError {}
+
+// lets functions that still report errors as `String` use `?` on a `GRBError`
+impl From<GRBError> for String {
+    fn from(value: GRBError) -> Self {
+        value.to_string()
+    }
+}
+
+/// Turn the error code of a call on `model` into a `GRBError` with the message of the model.
+pub(crate) fn check_model_err(model: *mut ffi::GRBmodel, error_code: i32) -> Result<(), GRBError> {
+    match check_err(error_code) {
+        Err(e) => Err(GRBError::new(e, unsafe {
+            CStr::from_ptr(ffi::GRBgetmerrormsg(model)).to_string_lossy()
+        })),
+        Ok(_o) => Ok(()),
+    }
+}
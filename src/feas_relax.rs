@@ -0,0 +1,240 @@
+// Feasibility relaxation: turn an infeasible model into one that minimizes the violation of
+// bounds and constraints, with a penalty per bound or constraint.
+//
+// `GRBfeasrelax` modifies the model in place and appends an artificial variable per relaxed bound
+// or constraint, named after what it relaxes: `ArtL_<var>`, `ArtU_<var>` for the lower and upper
+// bound of a variable and `ArtP_<constr>`, `ArtN_<constr>` for a constraint. They are added in
+// the order of the penalty vectors, which is how artificials with the same name (from variables or
+// constraints with the same name) are told apart.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    ptr::null_mut,
+};
+
+use crate::{
+    attributes::{GRBAttrObject, GRBCharAttr, GRBDblAttr, GRBIntAttr, GRBStrAttr},
+    constr::GRBConstr,
+    error::{check_model_err, GRBError},
+    ffi,
+    model::GRBModel,
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+/// How the violation is measured in a feasibility relaxation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RelaxType {
+    /// Weighted sum of the violations
+    Linear,
+    /// Weighted sum of the squared violations
+    Quadratic,
+    /// Weighted number of violated bounds and constraints
+    Cardinality,
+}
+
+impl From<RelaxType> for i32 {
+    fn from(value: RelaxType) -> Self {
+        match value {
+            RelaxType::Linear => 0,
+            RelaxType::Quadratic => 1,
+            RelaxType::Cardinality => 2,
+        }
+    }
+}
+
+/// What an artificial variable of a feasibility relaxation relaxes.
+pub enum Relaxed {
+    LowerBound(GRBVar),
+    UpperBound(GRBVar),
+    /// Artificial variable with coefficient +1 in the constraint
+    ConstrPositive(GRBConstr),
+    /// Artificial variable with coefficient -1 in the constraint
+    ConstrNegative(GRBConstr),
+}
+
+/// Result of `GRBModel::feas_relax`.
+pub struct FeasRelaxResult {
+    /// Objective value of the relaxation (the minimal violation), only computed if `min_relax`
+    /// was set
+    pub feas_obj: Option<f64>,
+    /// Every artificial variable that was added, with what it relaxes
+    pub artificials: Vec<(GRBVar, Relaxed)>,
+}
+
+impl GRBModel {
+    /// Replace the model by its feasibility relaxation.
+    ///
+    /// Only the bounds in `var_penalties` (`(var, lb penalty, ub penalty)`) and the constraints
+    /// in `constr_penalties` can be violated, use `GRB_INFINITY` as penalty to keep a single bound
+    /// fixed. With `min_relax` the relaxation is solved right away, the model then finds the
+    /// solution with minimal violation that is best for the original objective.
+    pub fn feas_relax(
+        &mut self,
+        relax_type: RelaxType,
+        min_relax: bool,
+        var_penalties: &[(&GRBVar, f64, f64)],
+        constr_penalties: &[(&GRBConstr, f64)],
+    ) -> Result<FeasRelaxResult, GRBError> {
+        self.update()?;
+        let num_vars = self.get(GRBIntAttr::NUMVARS)? as usize;
+        let num_constrs = self.get(GRBIntAttr::NUMCONSTRS)? as usize;
+        let mut lb_pen = vec![ffi::GRB_INFINITY; num_vars];
+        let mut ub_pen = vec![ffi::GRB_INFINITY; num_vars];
+        for (var, lb, ub) in var_penalties {
+            if !self.owns(&var.inner) || var.index() >= num_vars {
+                return Err(GRBError::new(
+                    ffi::GRB_ERROR_INVALID_ARGUMENT,
+                    format!("variable {} is not a variable of this model", var.index()),
+                ));
+            }
+            lb_pen[var.index()] = *lb;
+            ub_pen[var.index()] = *ub;
+        }
+        let mut rhs_pen = vec![ffi::GRB_INFINITY; num_constrs];
+        for (constr, pen) in constr_penalties {
+            if !self.owns(&constr.inner)
+                || constr.kind != GRBAttrObject::Constr
+                || constr.index >= num_constrs
+            {
+                return Err(GRBError::new(
+                    ffi::GRB_ERROR_INVALID_ARGUMENT,
+                    format!(
+                        "{:?} {} is not a linear constraint of this model",
+                        constr.kind, constr.index
+                    ),
+                ));
+            }
+            rhs_pen[constr.index] = *pen;
+        }
+        self.feas_relax_with(relax_type, min_relax, lb_pen, ub_pen, rhs_pen)
+    }
+
+    /// Replace the model by its feasibility relaxation where every bound and every linear
+    /// constraint can be violated with a penalty of 1, see `feas_relax`.
+    pub fn feas_relax_all(
+        &mut self,
+        relax_type: RelaxType,
+        min_relax: bool,
+    ) -> Result<FeasRelaxResult, GRBError> {
+        self.update()?;
+        let num_vars = self.get(GRBIntAttr::NUMVARS)? as usize;
+        let num_constrs = self.get(GRBIntAttr::NUMCONSTRS)? as usize;
+        self.feas_relax_with(
+            relax_type,
+            min_relax,
+            vec![1.0; num_vars],
+            vec![1.0; num_vars],
+            vec![1.0; num_constrs],
+        )
+    }
+
+    fn feas_relax_with(
+        &mut self,
+        relax_type: RelaxType,
+        min_relax: bool,
+        mut lb_pen: Vec<f64>,
+        mut ub_pen: Vec<f64>,
+        mut rhs_pen: Vec<f64>,
+    ) -> Result<FeasRelaxResult, GRBError> {
+        let num_vars = lb_pen.len();
+        let num_constrs = rhs_pen.len();
+        let mut expected = self.expected_artificials(&lb_pen, &ub_pen, &rhs_pen)?;
+
+        let mut feas_obj = 0.0;
+        let error = unsafe {
+            ffi::GRBfeasrelax(
+                *self.inner.0,
+                relax_type.into(),
+                min_relax as i32,
+                lb_pen.as_mut_ptr(),
+                ub_pen.as_mut_ptr(),
+                rhs_pen.as_mut_ptr(),
+                if min_relax { &mut feas_obj } else { null_mut() },
+            )
+        };
+        check_model_err(*self.inner.0, error)?;
+        self.update()?;
+
+        // keep the handle counters in sync with the variables and constraints that were added
+        let new_num_vars = self.get(GRBIntAttr::NUMVARS)? as usize;
+        let new_num_constrs = self.get(GRBIntAttr::NUMCONSTRS)? as usize;
+        self.var_index += new_num_vars - num_vars;
+        self.cons_index += new_num_constrs - num_constrs;
+
+        let new_names = self.get_all(GRBStrAttr::VARNAME)?.split_off(num_vars);
+        let mut artificials = Vec::with_capacity(new_names.len());
+        for (offset, name) in new_names.into_iter().enumerate() {
+            let var = GRBVar::new(num_vars + offset, self.inner(), None);
+            match expected.get_mut(&name).and_then(VecDeque::pop_front) {
+                Some(relaxed) => artificials.push((var, relaxed)),
+                None => {
+                    return Err(GRBError::new(
+                        ffi::GRB_ERROR_DATA_NOT_AVAILABLE,
+                        format!(
+                            "no bound or constraint found for artificial variable {}",
+                            name
+                        ),
+                    ))
+                }
+            }
+        }
+        Ok(FeasRelaxResult {
+            feas_obj: min_relax.then_some(feas_obj),
+            artificials,
+        })
+    }
+
+    // what the artificial variables with a given name relax, in the order gurobi adds them: the
+    // finite lower and upper bound of every variable with a finite penalty, then the violation
+    // direction(s) of every constraint with a finite penalty
+    fn expected_artificials(
+        &self,
+        lb_pen: &[f64],
+        ub_pen: &[f64],
+        rhs_pen: &[f64],
+    ) -> Result<HashMap<String, VecDeque<Relaxed>>, GRBError> {
+        let mut expected: HashMap<String, VecDeque<Relaxed>> = HashMap::new();
+        let mut expect = |name: String, relaxed: Relaxed| {
+            expected.entry(name).or_default().push_back(relaxed);
+        };
+        let var = |index| GRBVar::new(index, self.inner(), None);
+        let constr = |index| GRBConstr {
+            index,
+            kind: GRBAttrObject::Constr,
+            inner: self.inner(),
+        };
+        let names = self.get_all(GRBStrAttr::VARNAME)?;
+        let lbs = self.get_all(GRBDblAttr::LB)?;
+        let ubs = self.get_all(GRBDblAttr::UB)?;
+        for (index, name) in names.iter().enumerate() {
+            if lb_pen[index] < ffi::GRB_INFINITY && lbs[index] > -ffi::GRB_INFINITY {
+                expect(format!("ArtL_{}", name), Relaxed::LowerBound(var(index)));
+            }
+            if ub_pen[index] < ffi::GRB_INFINITY && ubs[index] < ffi::GRB_INFINITY {
+                expect(format!("ArtU_{}", name), Relaxed::UpperBound(var(index)));
+            }
+        }
+        let names = self.get_all(GRBStrAttr::CONSTRNAME)?;
+        let senses = self.get_all(GRBCharAttr::SENSE)?;
+        for (index, name) in names.iter().enumerate() {
+            if rhs_pen[index] >= ffi::GRB_INFINITY {
+                continue;
+            }
+            // `<=` rows can only be violated upwards, `>=` rows downwards
+            if senses[index] != '<' {
+                expect(
+                    format!("ArtP_{}", name),
+                    Relaxed::ConstrPositive(constr(index)),
+                );
+            }
+            if senses[index] != '>' {
+                expect(
+                    format!("ArtN_{}", name),
+                    Relaxed::ConstrNegative(constr(index)),
+                );
+            }
+        }
+        Ok(expected)
+    }
+}
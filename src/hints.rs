@@ -0,0 +1,60 @@
+// Variable hints: values (`VarHintVal`) that guide the MIP search towards solutions close to
+// them, with a priority (`VarHintPri`) per variable. Unlike a MIP start, a hint does not have to
+// be part of a feasible solution.
+
+use crate::{
+    attributes::{GRBDblAttr, GRBIntAttr},
+    error::{check_model_err, GRBError},
+    ffi,
+    model::{GRBModel, ModelSetterArray, ModelSetterList},
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+impl GRBModel {
+    /// Set hints as `(variable, value, priority)`, with a single attribute write for all values
+    /// and one for all priorities. A higher priority means more confidence in the hint.
+    ///
+    /// Hints can also be read from and written to `.hnt` files with `model.read` and
+    /// `model.write`.
+    ///
+    /// Returns an error if a variable belongs to another model.
+    pub fn set_hints(&mut self, hints: &[(&GRBVar, f64, i32)]) -> Result<(), GRBError> {
+        if let Some((var, _, _)) = hints.iter().find(|(var, _, _)| !self.owns(&var.inner)) {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!("variable {} is not a variable of this model", var.index()),
+            ));
+        }
+        let vars: Vec<&GRBVar> = hints.iter().map(|(var, _, _)| *var).collect();
+        let values: Vec<f64> = hints.iter().map(|(_, value, _)| *value).collect();
+        let priorities: Vec<i32> = hints.iter().map(|(_, _, priority)| *priority).collect();
+        let error = GRBDblAttr::VARHINTVAL.set_list(*self.inner.0, &vars, &values);
+        check_model_err(*self.inner.0, error)?;
+        let error = GRBIntAttr::VARHINTPRI.set_list(*self.inner.0, &vars, &priorities);
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// The hint of every variable as `(value, priority)`, `None` for variables without a hint.
+    pub fn hints(&mut self) -> Result<Vec<Option<(f64, i32)>>, GRBError> {
+        self.update()?;
+        let values = self.get_all(GRBDblAttr::VARHINTVAL)?;
+        let priorities = self.get_all(GRBIntAttr::VARHINTPRI)?;
+        Ok(values
+            .into_iter()
+            .zip(priorities)
+            .map(|(value, priority)| (value != ffi::GRB_UNDEFINED).then_some((value, priority)))
+            .collect())
+    }
+
+    /// Remove the hints of all variables.
+    pub fn clear_hints(&mut self) -> Result<(), GRBError> {
+        self.update()?;
+        let num_vars = self.get(GRBIntAttr::NUMVARS)? as usize;
+        let error =
+            GRBDblAttr::VARHINTVAL.set_array(*self.inner.0, 0, &vec![ffi::GRB_UNDEFINED; num_vars]);
+        check_model_err(*self.inner.0, error)?;
+        let error = GRBIntAttr::VARHINTPRI.set_array(*self.inner.0, 0, &vec![0; num_vars]);
+        check_model_err(*self.inner.0, error)
+    }
+}
@@ -0,0 +1,288 @@
+// Irreducible Inconsistent Subsystem (IIS) of an infeasible model: a subset of the constraints
+// and variable bounds that is infeasible, but becomes feasible if any single member is removed.
+
+use std::cell::RefCell;
+
+use crate::{
+    attributes::{
+        kind::{self, ConstrKind, ElementKind},
+        GRBAttrObject, GRBIntAttr, GRBStrAttr,
+    },
+    callback::{
+        CallbackTrait, GRBCallback, GRBCallbackCodes, GRBCallbackContext, GRB_WHAT_DOUBLE,
+        GRB_WHAT_INT,
+    },
+    constr::GRBConstr,
+    error::{check_model_err, GRBError},
+    ffi,
+    model::{GRBModel, ModelSetterList},
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+/// Whether a constraint or bound must be part of the IIS, set with
+/// `GRBModel::set_iis_constr_force`, `set_iis_lb_force` or `set_iis_ub_force`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IisForce {
+    /// Let the algorithm decide (the default)
+    Auto,
+    /// Never include it in the IIS
+    Exclude,
+    /// Always include it in the IIS
+    Include,
+}
+
+impl From<IisForce> for i32 {
+    fn from(value: IisForce) -> Self {
+        match value {
+            IisForce::Auto => -1,
+            IisForce::Exclude => 0,
+            IisForce::Include => 1,
+        }
+    }
+}
+
+/// Snapshot of a running IIS computation, from the `IIS` callback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IisProgress {
+    /// Elapsed time in seconds
+    pub runtime: f64,
+    /// Constraints known to be in the IIS
+    pub constr_min: i32,
+    /// Constraints that may still be in the IIS
+    pub constr_max: i32,
+    /// Estimate of the number of constraints in the IIS
+    pub constr_guess: i32,
+    /// Bounds known to be in the IIS
+    pub bound_min: i32,
+    /// Bounds that may still be in the IIS
+    pub bound_max: i32,
+    /// Estimate of the number of bounds in the IIS
+    pub bound_guess: i32,
+}
+
+impl IisProgress {
+    fn from_context(ctx: &mut GRBCallbackContext) -> Option<IisProgress> {
+        if !matches!(GRBCallbackCodes::from(ctx.where_), GRBCallbackCodes::IIS) {
+            return None;
+        }
+        Some(IisProgress {
+            runtime: ctx.get_info(GRB_WHAT_DOUBLE::RUNTIME).ok()?,
+            constr_min: ctx.get_info(GRB_WHAT_INT::IIS_CONSTRMIN).ok()?,
+            constr_max: ctx.get_info(GRB_WHAT_INT::IIS_CONSTRMAX).ok()?,
+            constr_guess: ctx.get_info(GRB_WHAT_INT::IIS_CONSTRGUESS).ok()?,
+            bound_min: ctx.get_info(GRB_WHAT_INT::IIS_BOUNDMIN).ok()?,
+            bound_max: ctx.get_info(GRB_WHAT_INT::IIS_BOUNDMAX).ok()?,
+            bound_guess: ctx.get_info(GRB_WHAT_INT::IIS_BOUNDGUESS).ok()?,
+        })
+    }
+}
+
+struct IisCallback<F: FnMut(IisProgress)> {
+    on_progress: RefCell<F>,
+}
+
+impl<F: FnMut(IisProgress)> CallbackTrait for IisCallback<F> {
+    fn callback(&self, mut cb_ctx: GRBCallbackContext) {
+        if let Some(progress) = IisProgress::from_context(&mut cb_ctx) {
+            (self.on_progress.borrow_mut())(progress);
+        }
+    }
+}
+
+/// The IIS of a model, created with `GRBModel::compute_iis`.
+///
+/// Constraints and bounds are listed as handles with their names, SOS constraints by index.
+pub struct IisReport {
+    /// Whether the IIS is minimal, it may not be if the computation was interrupted
+    pub minimal: bool,
+    pub constrs: Vec<(GRBConstr, String)>,
+    /// Variables whose lower bound is in the IIS
+    pub lower_bounds: Vec<(GRBVar, String)>,
+    /// Variables whose upper bound is in the IIS
+    pub upper_bounds: Vec<(GRBVar, String)>,
+    pub qconstrs: Vec<(GRBConstr, String)>,
+    pub gen_constrs: Vec<(GRBConstr, String)>,
+    pub sos: Vec<usize>,
+}
+
+impl IisReport {
+    /// The total number of constraints and bounds in the IIS.
+    pub fn len(&self) -> usize {
+        self.constrs.len()
+            + self.lower_bounds.len()
+            + self.upper_bounds.len()
+            + self.qconstrs.len()
+            + self.gen_constrs.len()
+            + self.sos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// indices of the members of an IIS membership attribute, e.g. `IIS_CONSTR`
+fn members(flags: Vec<i32>) -> impl Iterator<Item = usize> {
+    flags
+        .into_iter()
+        .enumerate()
+        .filter(|(_, flag)| *flag != 0)
+        .map(|(index, _)| index)
+}
+
+impl GRBModel {
+    /// Compute an IIS of this infeasible model.
+    ///
+    /// Constraints and bounds forced in or out with `set_iis_constr_force`, `set_iis_lb_force` and
+    /// `set_iis_ub_force` are honored. The IIS can be
+    /// written to an `.ilp` file afterwards with `model.write`.
+    pub fn compute_iis(&mut self) -> Result<IisReport, GRBError> {
+        let error = unsafe { ffi::GRBcomputeIIS(*self.inner.0) };
+        check_model_err(*self.inner.0, error)?;
+        self.iis_report()
+    }
+
+    /// Compute an IIS as `compute_iis`, and call `on_progress` from the `IIS` callback.
+    ///
+    /// This installs its own callback, replacing any callback registered with `set_callback`.
+    pub fn compute_iis_with_progress<F: FnMut(IisProgress)>(
+        &mut self,
+        on_progress: F,
+    ) -> Result<IisReport, GRBError> {
+        let mut callback = GRBCallback::new(IisCallback {
+            on_progress: RefCell::new(on_progress),
+        });
+        self.set_callback(&mut callback);
+        let error = unsafe { ffi::GRBcomputeIIS(*self.inner.0) };
+        // the callback is dropped at the end of this function, gurobi may not keep a pointer to it
+        self.clear_callback();
+        check_model_err(*self.inner.0, error)?;
+        self.iis_report()
+    }
+
+    /// Force linear, quadratic or general constraints in or out of the IIS, e.g.
+    /// `model.set_iis_constr_force(&[&c1, &c2], IisForce::Include)`.
+    pub fn set_iis_constr_force(
+        &mut self,
+        constrs: &[&GRBConstr],
+        force: IisForce,
+    ) -> Result<(), GRBError> {
+        if let Some(constr) = constrs.iter().find(|c| !self.owns(&c.inner)) {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!(
+                    "{:?} {} is not a constraint of this model",
+                    constr.kind, constr.index
+                ),
+            ));
+        }
+        self.set_iis_kind_force(GRBIntAttr::IIS_CONSTRFORCE, constrs, force)?;
+        self.set_iis_kind_force(GRBIntAttr::IIS_QCONSTRFORCE, constrs, force)?;
+        self.set_iis_kind_force(GRBIntAttr::IIS_GENCONSTRFORCE, constrs, force)
+    }
+
+    /// Force the lower bounds of `vars` in or out of the IIS.
+    pub fn set_iis_lb_force(&mut self, vars: &[&GRBVar], force: IisForce) -> Result<(), GRBError> {
+        self.set_iis_var_force(GRBIntAttr::IIS_LBFORCE, vars, force)
+    }
+
+    /// Force the upper bounds of `vars` in or out of the IIS.
+    pub fn set_iis_ub_force(&mut self, vars: &[&GRBVar], force: IisForce) -> Result<(), GRBError> {
+        self.set_iis_var_force(GRBIntAttr::IIS_UBFORCE, vars, force)
+    }
+
+    fn set_iis_var_force(
+        &mut self,
+        what: GRBIntAttr<kind::Var>,
+        vars: &[&GRBVar],
+        force: IisForce,
+    ) -> Result<(), GRBError> {
+        if let Some(var) = vars.iter().find(|v| !self.owns(&v.inner)) {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!("variable {} is not a variable of this model", var.index()),
+            ));
+        }
+        self.set_iis_force(what, vars, force)
+    }
+
+    // force the constraints among `constrs` that are of the kind of `what`
+    fn set_iis_kind_force<K: ConstrKind>(
+        &mut self,
+        what: GRBIntAttr<K>,
+        constrs: &[&GRBConstr],
+        force: IisForce,
+    ) -> Result<(), GRBError> {
+        let of_kind: Vec<&GRBConstr> = constrs
+            .iter()
+            .copied()
+            .filter(|c| c.kind == what.object())
+            .collect();
+        if of_kind.is_empty() {
+            return Ok(());
+        }
+        self.set_iis_force(what, &of_kind, force)
+    }
+
+    fn set_iis_force<K: ElementKind, C: IsModelingObject>(
+        &mut self,
+        what: GRBIntAttr<K>,
+        objects: &[C],
+        force: IisForce,
+    ) -> Result<(), GRBError> {
+        let values = vec![force.into(); objects.len()];
+        let error = what.set_list(*self.inner.0, objects, &values);
+        check_model_err(*self.inner.0, error)
+    }
+
+    fn iis_report(&self) -> Result<IisReport, GRBError> {
+        let var_names = self.get_all(GRBStrAttr::VARNAME)?;
+        let constr_names = self.get_all(GRBStrAttr::CONSTRNAME)?;
+        let qconstr_names = self.get_all(GRBStrAttr::QCNAME)?;
+        let gen_constr_names = self.get_all(GRBStrAttr::GENCONSTRNAME)?;
+        let var = |index: usize| {
+            (
+                GRBVar::new(index, self.inner(), None),
+                var_names[index].clone(),
+            )
+        };
+        let constrs = |kind: GRBAttrObject, flags: Vec<i32>, names: &[String]| {
+            members(flags)
+                .map(|index| {
+                    let constr = GRBConstr {
+                        index,
+                        kind,
+                        inner: self.inner(),
+                    };
+                    (constr, names[index].clone())
+                })
+                .collect()
+        };
+        Ok(IisReport {
+            minimal: self.get(GRBIntAttr::IIS_MINIMAL)? != 0,
+            constrs: constrs(
+                GRBAttrObject::Constr,
+                self.get_all(GRBIntAttr::IIS_CONSTR)?,
+                &constr_names,
+            ),
+            lower_bounds: members(self.get_all(GRBIntAttr::IIS_LB)?)
+                .map(var)
+                .collect(),
+            upper_bounds: members(self.get_all(GRBIntAttr::IIS_UB)?)
+                .map(var)
+                .collect(),
+            qconstrs: constrs(
+                GRBAttrObject::QConstr,
+                self.get_all(GRBIntAttr::IIS_QCONSTR)?,
+                &qconstr_names,
+            ),
+            gen_constrs: constrs(
+                GRBAttrObject::GenConstr,
+                self.get_all(GRBIntAttr::IIS_GENCONSTR)?,
+                &gen_constr_names,
+            ),
+            sos: members(self.get_all(GRBIntAttr::IIS_SOS)?).collect(),
+        })
+    }
+}
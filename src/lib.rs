@@ -1,12 +1,31 @@
 pub use gurobirs_sys as ffi;
 
+mod asynchronous;
 mod attributes;
+mod basis;
+mod batch;
 mod callback;
 mod constr;
+mod derived;
 mod env;
 mod error;
+mod feas_relax;
+mod hints;
+mod iis;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
+mod mip_start;
 mod model;
 mod modeling;
+mod outcome;
+mod param_set;
+mod param_values;
 mod parameters;
+mod pool;
 pub mod prelude;
+mod progress;
+mod sensitivity;
+mod solution;
+mod termination;
+mod tuning;
 mod var;
@@ -0,0 +1,131 @@
+// Forwarding of the Gurobi log to the `log` and/or `tracing` ecosystems.
+//
+// Gurobi calls a log callback ([GRBsetlogcallbackfunc](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/logging.html#c.GRBsetlogcallbackfunc))
+// for every message it writes. We register `log_shim` as that callback and pass a pointer to a
+// boxed `LogForwarder` as the user data. The box is owned by the `GRBModel`, so it lives as long
+// as the callback is registered.
+
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[cfg(feature = "tracing")]
+use crate::attributes::GRBIntAttr;
+use crate::error::{check_model_err, GRBError};
+use crate::ffi;
+use crate::model::GRBModel;
+#[cfg(feature = "tracing")]
+use crate::outcome::dbl_attr;
+
+pub(crate) struct LogForwarder {
+    target: String,
+}
+
+impl LogForwarder {
+    fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+        }
+    }
+
+    fn emit(&self, line: &str) {
+        #[cfg(feature = "log")]
+        log::info!(target: self.target.as_str(), "{}", line);
+        // `tracing` requires a static target, the configured target is recorded as a field instead
+        #[cfg(feature = "tracing")]
+        tracing::info!(target: "gurobi", log_target = self.target.as_str(), "{}", line);
+    }
+}
+
+unsafe extern "C" fn log_shim(msg: *mut c_char, logdata: *mut c_void) -> c_int {
+    if msg.is_null() || logdata.is_null() {
+        return 0;
+    }
+    let forwarder = logdata as *const LogForwarder;
+    let result = catch_unwind(AssertUnwindSafe(|| unsafe {
+        let msg = CStr::from_ptr(msg).to_string_lossy();
+        // Gurobi may hand us several lines at once, emit one record per line
+        for line in msg.lines().filter(|l| !l.trim().is_empty()) {
+            (*forwarder).emit(line);
+        }
+    }));
+    match result {
+        Ok(_) => 0,
+        Err(_) => ffi::GRB_ERROR_CALLBACK,
+    }
+}
+
+impl GRBModel {
+    /// Forward every line of the Gurobi log of this model as a record with the given `target`.
+    ///
+    /// With the `log` feature, lines are emitted as `log::info!` records with `target`. With the
+    /// `tracing` feature, lines are emitted as `tracing` events with target `"gurobi"` and the
+    /// configured target in the `log_target` field.
+    ///
+    /// Combine this with `GRBIntParam::LOGTOCONSOLE` set to `0` to stop Gurobi from printing the
+    /// log to stdout as well.
+    pub fn set_log_target(&mut self, target: &str) -> Result<(), GRBError> {
+        let forwarder = Box::new(LogForwarder::new(target));
+        let error = unsafe {
+            ffi::GRBsetlogcallbackfunc(
+                *self.inner.0,
+                Some(log_shim),
+                &*forwarder as *const LogForwarder as *mut c_void,
+            )
+        };
+        check_model_err(*self.inner.0, error)?;
+        // replacing the old forwarder is fine, gurobi no longer references it
+        self.log_forwarder = Some(forwarder);
+        Ok(())
+    }
+
+    /// Stop forwarding the Gurobi log of this model.
+    pub fn clear_log_target(&mut self) -> Result<(), GRBError> {
+        let error =
+            unsafe { ffi::GRBsetlogcallbackfunc(*self.inner.0, None, std::ptr::null_mut()) };
+        check_model_err(*self.inner.0, error)?;
+        self.log_forwarder = None;
+        Ok(())
+    }
+}
+
+/// Emit the structured `tracing` event that marks the start of `GRBModel::optimize` (or
+/// `optimize_async`).
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_optimize_start(model: &GRBModel) {
+    // the optimization processes pending changes anyway, do it first so they are counted
+    unsafe {
+        ffi::GRBupdatemodel(*model.inner.0);
+    }
+    let count = |attr| model.get(attr).ok();
+    tracing::info!(
+        target: "gurobirs",
+        num_vars = ?count(GRBIntAttr::NUMVARS),
+        num_constrs = ?count(GRBIntAttr::NUMCONSTRS),
+        num_qconstrs = ?count(GRBIntAttr::NUMQCONSTRS),
+        num_genconstrs = ?count(GRBIntAttr::NUMGENCONSTRS),
+        "optimize started"
+    );
+}
+
+/// Emit the structured `tracing` event that marks the end of `GRBModel::optimize` (or of the
+/// asynchronous optimization), carrying the status, runtime, objective and gap read from the
+/// model attributes.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_optimize_end(model: &GRBModel) {
+    let model = *model.inner.0;
+    let mut status = 0;
+    let error =
+        unsafe { ffi::GRBgetintattr(model, ffi::GRB_INT_ATTR_STATUS.as_ptr(), &mut status) };
+    let status = (error == 0).then_some(status);
+    let runtime = dbl_attr(model, ffi::GRB_DBL_ATTR_RUNTIME);
+    let obj_val = dbl_attr(model, ffi::GRB_DBL_ATTR_OBJVAL);
+    let mip_gap = dbl_attr(model, ffi::GRB_DBL_ATTR_MIPGAP);
+    tracing::info!(
+        target: "gurobirs",
+        status = ?status,
+        runtime = ?runtime,
+        obj_val = ?obj_val,
+        mip_gap = ?mip_gap,
+        "optimize finished"
+    );
+}
@@ -0,0 +1,102 @@
+// MIP starts: one or more (partial) assignments of the variables that Gurobi tries to complete
+// into a feasible solution before the branch-and-bound search starts.
+//
+// Every start is stored in the `Start` attribute, the `StartNumber` parameter selects which one
+// is read or written and `NumStart` holds the number of starts.
+
+use std::collections::HashMap;
+
+use crate::{
+    attributes::{GRBDblAttr, GRBIntAttr, GRBStrAttr},
+    error::{check_model_err, GRBError},
+    ffi,
+    model::{GRBModel, ModelSetter, ModelSetterArray},
+    modeling::IsModelingObject,
+    parameters::GRBIntParam,
+    solution::Solution,
+    var::GRBVar,
+};
+
+impl GRBModel {
+    /// Add a MIP start with the given variable values and return its number.
+    ///
+    /// Variables that are not in `values` are left undefined (`GRB_UNDEFINED`), Gurobi tries to
+    /// complete such a partial start. Every call adds a new start, MIP starts can also be read
+    /// from and written to `.mst` files with `model.read` and `model.write`.
+    ///
+    /// Returns an error if a variable belongs to another model.
+    pub fn add_mip_start(&mut self, values: &[(&GRBVar, f64)]) -> Result<usize, GRBError> {
+        self.update()?;
+        let mut start = vec![ffi::GRB_UNDEFINED; self.get(GRBIntAttr::NUMVARS)? as usize];
+        for (var, value) in values {
+            if !self.owns(&var.inner) || var.index() >= start.len() {
+                return Err(GRBError::new(
+                    ffi::GRB_ERROR_INVALID_ARGUMENT,
+                    format!("variable {} is not a variable of this model", var.index()),
+                ));
+            }
+            start[var.index()] = *value;
+        }
+        self.push_mip_start(&start)
+    }
+
+    /// Add a MIP start from a solution of another (e.g. an earlier version of this) model, by
+    /// matching variable names. Returns the number of the start and the number of variables of
+    /// this model that got a value.
+    ///
+    /// Variables without a match in `solution` are left undefined, as in `add_mip_start`.
+    pub fn add_mip_start_from_solution(
+        &mut self,
+        solution: &Solution,
+    ) -> Result<(usize, usize), GRBError> {
+        self.update()?;
+        let values: HashMap<&str, f64> = solution.iter().collect();
+        let start: Vec<f64> = self
+            .get_all(GRBStrAttr::VARNAME)?
+            .iter()
+            .map(|name| *values.get(name.as_str()).unwrap_or(&ffi::GRB_UNDEFINED))
+            .collect();
+        let matched = start.iter().filter(|v| **v != ffi::GRB_UNDEFINED).count();
+        Ok((self.push_mip_start(&start)?, matched))
+    }
+
+    /// The number of MIP starts of the model (`NumStart`).
+    pub fn num_mip_starts(&self) -> Result<usize, GRBError> {
+        Ok(self.get(GRBIntAttr::NUMSTART)? as usize)
+    }
+
+    /// The values of MIP start `number`, `None` for variables without a value.
+    pub fn mip_start(&mut self, number: usize) -> Result<Vec<Option<f64>>, GRBError> {
+        self.set_int_param(GRBIntParam::STARTNUMBER, number as i32)?;
+        let start = self.get_all(GRBDblAttr::START);
+        self.set_int_param(GRBIntParam::STARTNUMBER, 0)?;
+        Ok(start?
+            .into_iter()
+            .map(|v| (v != ffi::GRB_UNDEFINED).then_some(v))
+            .collect())
+    }
+
+    /// Remove all MIP starts.
+    pub fn clear_mip_starts(&mut self) -> Result<(), GRBError> {
+        self.set_num_starts(0)?;
+        self.update()
+    }
+
+    // append `start` (a value for every variable) as a new MIP start
+    fn push_mip_start(&mut self, start: &[f64]) -> Result<usize, GRBError> {
+        let number = self.num_mip_starts()?;
+        self.set_num_starts(number + 1)?;
+        self.update()?;
+        self.set_int_param(GRBIntParam::STARTNUMBER, number as i32)?;
+        let error = GRBDblAttr::START.set_array(*self.inner.0, 0, start);
+        // the values are stored in the start selected when the update is processed
+        let result = check_model_err(*self.inner.0, error).and_then(|_| self.update());
+        self.set_int_param(GRBIntParam::STARTNUMBER, 0)?;
+        result.map(|_| number)
+    }
+
+    fn set_num_starts(&mut self, count: usize) -> Result<(), GRBError> {
+        let error = GRBIntAttr::NUMSTART.set(*self.inner.0, count as i32);
+        check_model_err(*self.inner.0, error)
+    }
+}
@@ -1,23 +1,32 @@
 use std::{
+    borrow::Borrow,
     ffi::{c_char, CStr, CString},
     ptr::{null, null_mut},
     rc::Rc,
+    sync::{Arc, Mutex},
 };
 
 use crate::{
+    attributes::{GRBAttrObject, GRBIntAttr},
+    callback::RawCallback,
     constr::{GRBConstr, TempConstr, TempQConstr},
-    env::GRBenv,
-    error::check_err,
+    env::{self, GRBParams, GRBenv, GRBenvPtr, ParamScope},
+    error::{check_err, check_model_err, GRBError},
     ffi,
     modeling::{
         expr::nonlin_expr::GRBOpCode, AddAsIndicator, CanBeAddedToModel, IsModelingObject,
         Objective,
     },
+    outcome::SolveOutcome,
+    parameters::{GRBIntParam, ParamInfo},
     prelude::GRBVarBuilder,
+    termination::{ModelSlot, TerminationReason},
     var::GRBVar,
 };
 
-pub struct GRBModelPtr(pub(crate) Rc<*mut ffi::GRBmodel>);
+// The environment handle keeps the `GRBenv` the model was created in alive until the model is
+// freed, fields are dropped after `drop` below.
+pub struct GRBModelPtr(pub(crate) Rc<*mut ffi::GRBmodel>, pub(crate) Rc<GRBenvPtr>);
 
 impl Drop for GRBModelPtr {
     fn drop(&mut self) {
@@ -34,18 +43,34 @@ impl Drop for GRBModelPtr {
 
 impl Clone for GRBModelPtr {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self(self.0.clone(), self.1.clone())
     }
 }
 
 pub struct GRBModel {
     pub(crate) inner: GRBModelPtr,
-    var_index: usize,
-    cons_index: usize,
+    pub(crate) var_index: usize,
+    // gurobi numbers linear, quadratic and general constraints separately
+    pub(crate) cons_index: usize,
+    pub(crate) qcons_index: usize,
+    pub(crate) gencons_index: usize,
+    pub(crate) termination_reason: Option<TerminationReason>,
+    // the callback registered with `set_callback`
+    pub(crate) callback: Option<RawCallback>,
+    pub(crate) terminate_slot: Arc<Mutex<ModelSlot>>,
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub(crate) log_forwarder: Option<Box<crate::logging::LogForwarder>>,
 }
 
 impl GRBModel {
-    pub fn new(env: GRBenv) -> GRBModel {
+    /// Create an empty model in `env`.
+    ///
+    /// The model gets its own copy of the environment: parameters changed on the model afterwards
+    /// (see `GRBModel::params`) do not affect `env` or other models created from it. The model
+    /// keeps the environment alive, so `env` can be passed by value or by reference and dropped
+    /// before the model.
+    pub fn new<E: Borrow<GRBenv>>(env: E) -> GRBModel {
+        let env = env.borrow();
         let mut model = null_mut();
         let error = unsafe {
             ffi::GRBnewmodel(
@@ -62,10 +87,23 @@ impl GRBModel {
         };
         env.get_error(error).unwrap();
         // start indexes at 0 (per docs)
+        GRBModel::from_raw(model, env.handle())
+    }
+
+    // take ownership of a model created by gurobi, the caller sets the indexes if the model
+    // already has variables or constraints
+    pub(crate) fn from_raw(model: *mut ffi::GRBmodel, env: Rc<GRBenvPtr>) -> GRBModel {
         GRBModel {
-            inner: GRBModelPtr(Rc::new(model)),
+            inner: GRBModelPtr(Rc::new(model), env),
             var_index: 0,
             cons_index: 0,
+            qcons_index: 0,
+            gencons_index: 0,
+            termination_reason: None,
+            callback: None,
+            terminate_slot: Arc::new(Mutex::new(ModelSlot(model))),
+            #[cfg(any(feature = "log", feature = "tracing"))]
+            log_forwarder: None,
         }
     }
 
@@ -93,6 +131,28 @@ impl GRBModel {
         self.inner.clone()
     }
 
+    // whether a handle with model pointer `inner` belongs to this model
+    pub(crate) fn owns(&self, inner: &GRBModelPtr) -> bool {
+        Rc::ptr_eq(&self.inner.0, &inner.0)
+    }
+
+    // handle of the constraint of `kind` that was just added
+    fn next_constr(&mut self, kind: GRBAttrObject) -> GRBConstr {
+        let inner = self.inner();
+        let index = match kind {
+            GRBAttrObject::QConstr => &mut self.qcons_index,
+            GRBAttrObject::GenConstr => &mut self.gencons_index,
+            _ => &mut self.cons_index,
+        };
+        let constr = GRBConstr {
+            index: *index,
+            kind,
+            inner,
+        };
+        *index += 1;
+        constr
+    }
+
     pub fn add_constr(&mut self, mut expr: TempConstr) -> GRBConstr {
         let name = expr.get_name();
         let name_ptr = match name {
@@ -101,12 +161,7 @@ impl GRBModel {
         };
         let error = expr.add_to_model(*self.inner.0, name_ptr);
         self.get_error(error).unwrap();
-        let constr = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner.clone(),
-        };
-        self.cons_index += 1;
-        constr
+        self.next_constr(GRBAttrObject::Constr)
     }
 
     pub fn add_qconstr(&mut self, mut expr: TempQConstr) -> GRBConstr {
@@ -117,12 +172,7 @@ impl GRBModel {
         };
         let error = expr.add_to_model(*self.inner.0, name_ptr);
         self.get_error(error).unwrap();
-        let constr = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner.clone(),
-        };
-        self.cons_index += 1;
-        constr
+        self.next_constr(GRBAttrObject::QConstr)
     }
 
     pub fn add_genconstr_max(
@@ -151,12 +201,7 @@ impl GRBModel {
         };
         self.get_error(error).unwrap();
 
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_min(
@@ -186,12 +231,7 @@ impl GRBModel {
         };
         self.get_error(error).unwrap();
 
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_abs(&mut self, res_var: GRBVar, arg_var: GRBVar, name: &str) -> GRBConstr {
@@ -206,12 +246,7 @@ impl GRBModel {
             )
         };
         self.get_error(error);
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_and(
@@ -237,12 +272,7 @@ impl GRBModel {
             )
         };
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_or(
@@ -268,12 +298,7 @@ impl GRBModel {
             )
         };
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_norm(
@@ -301,12 +326,7 @@ impl GRBModel {
             )
         };
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_indicator(
@@ -322,12 +342,7 @@ impl GRBModel {
         };
         let error = constr.add_as_indicator(*self.inner.0, binvar, binval, name_ptr);
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn add_genconstr_pwl(
@@ -361,12 +376,7 @@ impl GRBModel {
             )
         };
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     // TODO: Best to add a nonlinexpr struct that can be built using arithmetic operations (like
@@ -407,28 +417,60 @@ impl GRBModel {
             )
         };
         self.get_error(error).unwrap();
-        let cons = GRBConstr {
-            index: self.cons_index,
-            inner: self.inner(),
-        };
-        self.cons_index += 1;
-        cons
+        self.next_constr(GRBAttrObject::GenConstr)
     }
 
     pub fn set_objective<O: Objective>(&mut self, obj: O, sense: GRBModelSense) {
         obj.set_as_objective(self, sense);
     }
 
-    pub fn optimize(&mut self) {
-        let error = unsafe { ffi::GRBoptimize(*self.inner.0) };
-        match self.get_error(error) {
-            Ok(_) => (),
+    /// Optimize the model and return a summary of the result.
+    ///
+    /// Panics if the optimization fails, use `try_optimize` to handle the error instead.
+    pub fn optimize(&mut self) -> SolveOutcome {
+        match self.try_optimize() {
+            Ok(outcome) => outcome,
             Err(e) => {
                 panic!("{}", e);
             }
         }
     }
 
+    /// Optimize the model and return a summary of the result, or the error of the optimization.
+    pub fn try_optimize(&mut self) -> Result<SolveOutcome, GRBError> {
+        self.run_optimize()?;
+        self.solve_outcome()
+    }
+
+    /// The current optimization status of the model.
+    ///
+    /// Panics if the status cannot be read, which gurobi only reports for an invalid model.
+    pub fn status(&self) -> GRBStatus {
+        let mut status = 0;
+        let error = unsafe {
+            ffi::GRBgetintattr(
+                *self.inner.0,
+                ffi::GRB_INT_ATTR_STATUS.as_ptr(),
+                &mut status,
+            )
+        };
+        check_model_err(*self.inner.0, error)
+            .and_then(|_| GRBStatus::try_from(status))
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Run `GRBoptimize` without reading the outcome. Used by the optimize variants that need to
+    /// clean up (e.g. unregister a callback) before the outcome is read or an error is returned.
+    pub(crate) fn run_optimize(&mut self) -> Result<(), GRBError> {
+        self.termination_reason = None;
+        #[cfg(feature = "tracing")]
+        crate::logging::trace_optimize_start(self);
+        let error = unsafe { ffi::GRBoptimize(*self.inner.0) };
+        #[cfg(feature = "tracing")]
+        crate::logging::trace_optimize_end(self);
+        check_model_err(*self.inner.0, error)
+    }
+
     pub fn get_error(&self, error_code: i32) -> Result<(), String> {
         match check_err(error_code) {
             Err(e) => unsafe {
@@ -448,20 +490,196 @@ impl GRBModel {
         self.get_error(error).unwrap();
     }
 
-    pub fn set_list<C, S>(&mut self, what: S, inds: Vec<C>, values: Vec<S::Value>)
+    /// Set an attribute of several variables or constraints at once, `values[i]` is the value
+    /// of `inds[i]`.
+    ///
+    /// Returns an error if a handle belongs to another model or is of another kind than the
+    /// attribute, e.g. a variable for a constraint attribute.
+    pub fn set_list<C, S>(
+        &mut self,
+        what: S,
+        inds: &[C],
+        values: &[S::Value],
+    ) -> Result<(), GRBError>
     where
         C: IsModelingObject,
         S: ModelSetterList<C>,
     {
+        self.check_owned(inds)?;
         let error = what.set_list(*self.inner.0, inds, values);
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// Set an attribute of the variables (or constraints) `first..first + values.len()` in a
+    /// single call.
+    pub fn set_array<S: ModelSetterArray>(&mut self, what: S, first: usize, values: &[S::Value]) {
+        let error = what.set_array(*self.inner.0, first, values);
         self.get_error(error).unwrap();
     }
 
-    pub fn get<G: ModelGetter>(&self, what: G) -> G::Value {
+    /// Get a model attribute, e.g. `model.get(GRBDblAttr::OBJVAL)`.
+    pub fn get<G: ModelGetter>(&self, what: G) -> Result<G::Value, GRBError> {
         what.get(*self.inner.0)
     }
+
+    /// Get an attribute of several variables or constraints at once.
+    ///
+    /// Returns an error if a handle belongs to another model or is of another kind than the
+    /// attribute.
+    pub fn get_list<C, G>(&self, what: G, inds: &[C]) -> Result<Vec<G::Value>, GRBError>
+    where
+        C: IsModelingObject,
+        G: ModelGetterList<C>,
+    {
+        self.check_owned(inds)?;
+        what.get_list(*self.inner.0, inds)
+    }
+
+    fn check_owned<C: IsModelingObject>(&self, handles: &[C]) -> Result<(), GRBError> {
+        match handles.iter().find(|handle| !self.owns(handle.model_ptr())) {
+            Some(handle) => Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!(
+                    "{:?} {} is not an object of this model",
+                    handle.object(),
+                    handle.index()
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Get an attribute of the variables (or constraints) `first..first + len` in a single call.
+    pub fn get_array<G: ModelGetterArray>(
+        &self,
+        what: G,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<G::Value>, GRBError> {
+        what.get_array(*self.inner.0, first, len)
+    }
+
+    /// Like `get_array`, but writes the values of `first..first + values.len()` into `values`
+    /// without allocating, e.g. to read `X` of millions of variables into a reused buffer.
+    pub fn get_array_into<G: ModelGetterArrayInto>(
+        &self,
+        what: G,
+        first: usize,
+        values: &mut [G::Value],
+    ) -> Result<(), GRBError> {
+        what.get_array_into(*self.inner.0, first, values)
+    }
+
+    /// Get an attribute of all objects of the kind it belongs to, e.g. `X` of all variables or
+    /// `PI` of all linear constraints. Objects that are not yet added with an update are not
+    /// included.
+    pub fn get_all<G: ModelGetterArray>(&self, what: G) -> Result<Vec<G::Value>, GRBError> {
+        let count = match what.object() {
+            GRBAttrObject::Var => GRBIntAttr::NUMVARS,
+            GRBAttrObject::Constr => GRBIntAttr::NUMCONSTRS,
+            GRBAttrObject::QConstr => GRBIntAttr::NUMQCONSTRS,
+            GRBAttrObject::GenConstr => GRBIntAttr::NUMGENCONSTRS,
+            GRBAttrObject::Sos => GRBIntAttr::NUMSOS,
+            GRBAttrObject::Model | GRBAttrObject::Batch => {
+                return Err(GRBError::new(
+                    ffi::GRB_ERROR_INVALID_ARGUMENT,
+                    "model and batch attributes have a single value, use `get`",
+                ))
+            }
+        };
+        let len = self.get(count)?;
+        what.get_array(*self.inner.0, 0, len as usize)
+    }
+
+    /// Process pending changes to the model, e.g. so attributes of new variables can be read.
+    pub fn update(&mut self) -> Result<(), GRBError> {
+        let error = unsafe { ffi::GRBupdatemodel(*self.inner.0) };
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// Read data into this model from a file, the type is determined by the extension, e.g. a
+    /// MIP start (`.mst`), hints (`.hnt`), a basis (`.bas`) or parameters (`.prm`).
+    pub fn read(&mut self, filename: &str) -> Result<(), GRBError> {
+        let filename = filename_cstring(filename)?;
+        let error = unsafe { ffi::GRBread(*self.inner.0, filename.as_ptr()) };
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// Write the model or data from it to a file, the type is determined by the extension, e.g.
+    /// the model (`.lp`, `.mps`), a solution (`.sol`) or a MIP start (`.mst`).
+    pub fn write(&self, filename: &str) -> Result<(), GRBError> {
+        let filename = filename_cstring(filename)?;
+        let error = unsafe { ffi::GRBwrite(*self.inner.0, filename.as_ptr()) };
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// View on the parameters of this model, i.e. of the copy of the environment this model owns.
+    pub fn params(&mut self) -> GRBParams<'_> {
+        GRBParams::new(self.get_env(), ParamScope::Model)
+    }
+
+    /// Get the value of a parameter from the environment of this model.
+    pub fn get_param<G: EnvGetter>(&self, what: G) -> Result<G::Value, GRBError> {
+        what.get(self.get_env())
+    }
+
+    // set an int parameter of this model, reporting errors as `GRBError`
+    pub(crate) fn set_int_param(&mut self, what: GRBIntParam, value: i32) -> Result<(), GRBError> {
+        let error = EnvSetter::set(&what, self.get_env(), value);
+        check_model_err(*self.inner.0, error)
+    }
+
+    /// Get the current, minimum, maximum and default value of a parameter from the environment of
+    /// this model.
+    pub fn param_info<G: EnvGetter>(&self, what: G) -> Result<ParamInfo<G::Value>, GRBError> {
+        what.info(self.get_env())
+    }
+
+    /// Reset all parameters of this model to their default values.
+    pub fn reset_params(&mut self) -> Result<(), GRBError> {
+        env::reset_params(self.get_env())
+    }
+
+    /// Read parameter settings from a `.prm` file into the environment of this model.
+    pub fn read_params(&mut self, filename: &str) -> Result<(), GRBError> {
+        env::read_params(self.get_env(), filename)
+    }
+
+    /// Write the non-default parameter settings of this model to a `.prm` file.
+    pub fn write_params(&self, filename: &str) -> Result<(), GRBError> {
+        env::write_params(self.get_env(), filename)
+    }
+}
+
+fn filename_cstring(filename: &str) -> Result<CString, GRBError> {
+    CString::new(filename).map_err(|_| {
+        GRBError::new(
+            ffi::GRB_ERROR_INVALID_ARGUMENT,
+            "Failed to convert filename to CString",
+        )
+    })
+}
+
+// The model itself may outlive this struct through the `GRBModelPtr` held by variables and
+// constraints, so anything that refers back to this struct is detached here.
+impl Drop for GRBModel {
+    fn drop(&mut self) {
+        // outstanding `TerminateHandle`s must not touch the model anymore
+        self.terminate_slot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .0 = null_mut();
+        // the log callback points into `log_forwarder`, unregister it before it is dropped
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        if self.log_forwarder.is_some() {
+            unsafe {
+                ffi::GRBsetlogcallbackfunc(*self.inner.0, None, null_mut());
+            }
+        }
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GRBModelSense {
     MAXIMIZE,
     MINIMIZE,
@@ -476,7 +694,16 @@ impl GRBModelSense {
     }
 }
 
+// the value of the `MODELSENSE` attribute, e.g. `model.set(GRBIntAttr::MODELSENSE, sense.into())`
+impl From<GRBModelSense> for i32 {
+    fn from(value: GRBModelSense) -> Self {
+        GRBModelSense::get(value)
+    }
+}
+
 #[allow(clippy::upper_case_acronyms, non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GRBStatus {
     LOADED,
     OPTIMAL,
@@ -525,9 +752,41 @@ impl From<GRBStatus> for std::ffi::c_int {
     }
 }
 
+impl TryFrom<std::ffi::c_int> for GRBStatus {
+    type Error = GRBError;
+
+    fn try_from(value: std::ffi::c_int) -> Result<Self, Self::Error> {
+        match value {
+            ffi::GRB_LOADED => Ok(GRBStatus::LOADED),
+            ffi::GRB_OPTIMAL => Ok(GRBStatus::OPTIMAL),
+            ffi::GRB_INFEASIBLE => Ok(GRBStatus::INFEASIBLE),
+            ffi::GRB_INF_OR_UNBD => Ok(GRBStatus::INF_OR_UNBD),
+            ffi::GRB_UNBOUNDED => Ok(GRBStatus::UNBOUNDED),
+            ffi::GRB_CUTOFF => Ok(GRBStatus::CUTOFF),
+            ffi::GRB_ITERATION_LIMIT => Ok(GRBStatus::ITERATION_LIMIT),
+            ffi::GRB_NODE_LIMIT => Ok(GRBStatus::NODE_LIMIT),
+            ffi::GRB_TIME_LIMIT => Ok(GRBStatus::TIME_LIMIT),
+            ffi::GRB_SOLUTION_LIMIT => Ok(GRBStatus::SOLUTION_LIMIT),
+            ffi::GRB_INTERRUPTED => Ok(GRBStatus::INTERRUPTED),
+            ffi::GRB_NUMERIC => Ok(GRBStatus::NUMERIC),
+            ffi::GRB_SUBOPTIMAL => Ok(GRBStatus::SUBOPTIMAL),
+            ffi::GRB_INPROGRESS => Ok(GRBStatus::INPROGRESS),
+            ffi::GRB_USER_OBJ_LIMIT => Ok(GRBStatus::USER_OBJ_LIMIT),
+            ffi::GRB_WORK_LIMIT => Ok(GRBStatus::WORK_LIMIT),
+            ffi::GRB_MEM_LIMIT => Ok(GRBStatus::MEM_LIMIT),
+            ffi::GRB_LOCALLY_OPTIMAL => Ok(GRBStatus::LOCALLY_OPTIMAL),
+            ffi::GRB_LOCALLY_INFEASIBLE => Ok(GRBStatus::LOCALLY_INFEASIBLE),
+            _ => Err(GRBError::new(
+                ffi::GRB_ERROR_VALUE_OUT_OF_RANGE,
+                format!("unknown optimization status {}", value),
+            )),
+        }
+    }
+}
+
 pub trait ModelGetter {
     type Value;
-    fn get(&self, model: *mut ffi::GRBmodel) -> Self::Value;
+    fn get(&self, model: *mut ffi::GRBmodel) -> Result<Self::Value, GRBError>;
 }
 
 pub trait ModelGetterList<C>
@@ -535,7 +794,37 @@ where
     C: IsModelingObject,
 {
     type Value;
-    fn get_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>) -> Vec<Self::Value>;
+    fn get_list(&self, model: *mut ffi::GRBmodel, inds: &[C])
+        -> Result<Vec<Self::Value>, GRBError>;
+}
+
+// trait used to get an attribute of a contiguous range of variables or constraints
+pub trait ModelGetterArray {
+    type Value;
+    /// The kind of object the attribute belongs to, used by `GRBModel::get_all`.
+    fn object(&self) -> GRBAttrObject;
+    fn get_array(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        len: usize,
+    ) -> Result<Vec<Self::Value>, GRBError>;
+}
+
+// trait used to get an attribute of a contiguous range into a caller-provided buffer
+pub trait ModelGetterArrayInto: ModelGetterArray {
+    fn get_array_into(
+        &self,
+        model: *mut ffi::GRBmodel,
+        first: usize,
+        values: &mut [Self::Value],
+    ) -> Result<(), GRBError>;
+}
+
+// trait used to set an attribute of a contiguous range of variables or constraints
+pub trait ModelSetterArray {
+    type Value;
+    fn set_array(&self, model: *mut ffi::GRBmodel, first: usize, values: &[Self::Value]) -> i32;
 }
 
 // trait used to set model attributes and parameters
@@ -548,7 +837,18 @@ pub trait EnvSetter {
     type Value;
     fn set(&self, env: *mut ffi::GRBenv, value: Self::Value) -> i32;
 }
+// trait used to get parameters (and their info) from an environment
+pub trait EnvGetter {
+    type Value;
+    fn get(&self, env: *mut ffi::GRBenv) -> Result<Self::Value, GRBError>;
+    fn info(&self, env: *mut ffi::GRBenv) -> Result<ParamInfo<Self::Value>, GRBError>;
+}
 // implement env setter for all modelsetters! We can access the env from the model
+//
+// NOTE: `GRBgetenv` returns the copy of the environment that gurobi made for this model when it was
+// created, so parameters set this way only affect this model (`ParamScope::Model`), never the
+// `GRBenv` the model was created from or sibling models. `GRBModel::params` exposes the same scope
+// explicitly.
 impl<E: EnvSetter> ModelSetter for E {
     type Value = E::Value;
 
@@ -565,7 +865,7 @@ where
     C: IsModelingObject,
 {
     type Value;
-    fn set_list(&self, model: *mut ffi::GRBmodel, inds: Vec<C>, values: Vec<Self::Value>) -> i32;
+    fn set_list(&self, model: *mut ffi::GRBmodel, inds: &[C], values: &[Self::Value]) -> i32;
 }
 
 // TODO: setters
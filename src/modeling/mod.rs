@@ -1,7 +1,8 @@
 use std::ffi::CString;
 
+use crate::attributes::GRBAttrObject;
 use crate::ffi;
-use crate::model::GRBModelSense;
+use crate::model::{GRBModelPtr, GRBModelSense};
 use crate::var::GRBVar;
 use crate::{model::GRBModel, prelude::GRBCallbackContext};
 
@@ -25,7 +26,26 @@ pub(crate) trait AddAsIndicator {
 /// This can then be used to implement generic functions that work with any modeling object
 pub trait IsModelingObject {
     fn index(&self) -> usize;
+    /// The kind of object, i.e. which attributes apply to it.
+    fn object(&self) -> GRBAttrObject;
+    /// The model this object belongs to.
+    fn model_ptr(&self) -> &GRBModelPtr;
 }
+
+impl<T: IsModelingObject> IsModelingObject for &T {
+    fn index(&self) -> usize {
+        (**self).index()
+    }
+
+    fn object(&self) -> GRBAttrObject {
+        (**self).object()
+    }
+
+    fn model_ptr(&self) -> &GRBModelPtr {
+        (**self).model_ptr()
+    }
+}
+
 // returns i32, because we need access to either a GRBModel or GRBEnv in order to handle errors
 pub trait CanBeAddedToCallback {
     fn add_cut(self, callback: &mut GRBCallbackContext) -> i32;
@@ -0,0 +1,59 @@
+// Summary of an optimization, returned by `GRBModel::optimize` and its variants.
+
+use std::ffi::CStr;
+
+use crate::{
+    error::{check_model_err, GRBError},
+    ffi,
+    model::{GRBModel, GRBStatus},
+};
+
+/// The result of an optimization, read from the model attributes right after it finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveOutcome {
+    pub status: GRBStatus,
+    /// Objective value of the best solution, `None` if no solution was found
+    pub obj_val: Option<f64>,
+    /// Best known bound on the objective, only available for MIPs
+    pub obj_bound: Option<f64>,
+    /// Relative optimality gap, only available for MIPs with a solution
+    pub mip_gap: Option<f64>,
+    /// Solver time in seconds
+    pub runtime: f64,
+    /// Number of solutions found
+    pub sol_count: i32,
+}
+
+impl SolveOutcome {
+    /// Whether a feasible solution is available, regardless of why the optimization stopped.
+    pub fn has_solution(&self) -> bool {
+        self.sol_count > 0
+    }
+}
+
+/// Read a double attribute of the model, returns `None` if it is not available.
+pub(crate) fn dbl_attr(model: *mut ffi::GRBmodel, attr_name: &CStr) -> Option<f64> {
+    let mut value = 0.0;
+    let error = unsafe { ffi::GRBgetdblattr(model, attr_name.as_ptr(), &mut value) };
+    (error == 0).then_some(value)
+}
+
+impl GRBModel {
+    pub(crate) fn solve_outcome(&self) -> Result<SolveOutcome, GRBError> {
+        let model = *self.inner.0;
+        let mut sol_count = 0;
+        let error = unsafe {
+            ffi::GRBgetintattr(model, ffi::GRB_INT_ATTR_SOLCOUNT.as_ptr(), &mut sol_count)
+        };
+        check_model_err(model, error)?;
+        let has_solution = sol_count > 0;
+        Ok(SolveOutcome {
+            status: self.status(),
+            obj_val: dbl_attr(model, ffi::GRB_DBL_ATTR_OBJVAL).filter(|_| has_solution),
+            obj_bound: dbl_attr(model, ffi::GRB_DBL_ATTR_OBJBOUND),
+            mip_gap: dbl_attr(model, ffi::GRB_DBL_ATTR_MIPGAP).filter(|_| has_solution),
+            runtime: dbl_attr(model, ffi::GRB_DBL_ATTR_RUNTIME).unwrap_or(0.0),
+            sol_count,
+        })
+    }
+}
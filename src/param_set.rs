@@ -0,0 +1,415 @@
+// A collection of parameter assignments that can be applied to an environment or model in one
+// call, and stored as a `.prm` file or (with the `serde` feature) in any serde format.
+
+use std::fmt;
+
+use crate::{
+    env::{GRBParams, GRBenv},
+    error::GRBError,
+    ffi,
+    model::{EnvSetter, GRBModel},
+    param_values::IntParamValue,
+    parameters::{GRBDblParam, GRBIntParam, GRBStrParam},
+};
+
+/// The value of a single parameter in a `ParamSet`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum ParamValue {
+    Int(i32),
+    Dbl(f64),
+    Str(String),
+}
+
+impl fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Int(value) => write!(f, "{}", value),
+            ParamValue::Dbl(value) => write!(f, "{}", value),
+            ParamValue::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A parameter type that can be stored in a `ParamSet`, used by `ParamSet::set`.
+pub trait ParamSetKey: EnvSetter + Copy {
+    fn insert_into(self, set: &mut ParamSet, value: Self::Value);
+}
+
+impl ParamSetKey for GRBIntParam {
+    fn insert_into(self, set: &mut ParamSet, value: i32) {
+        upsert(&mut set.int, self, value);
+    }
+}
+
+impl ParamSetKey for GRBDblParam {
+    fn insert_into(self, set: &mut ParamSet, value: f64) {
+        upsert(&mut set.dbl, self, value);
+    }
+}
+
+impl ParamSetKey for GRBStrParam {
+    fn insert_into(self, set: &mut ParamSet, value: String) {
+        upsert(&mut set.str, self, value);
+    }
+}
+
+/// A set of parameter assignments. Setting a parameter that is already in the set replaces its
+/// value, otherwise the order of insertion is kept.
+///
+/// ```ignore
+/// let mut params = ParamSet::new();
+/// params.set_typed(Method::Barrier).set_dbl(GRBDblParam::TIMELIMIT, 60.0);
+/// params.apply_to_model(&mut model)?;
+/// std::fs::write("tuned.prm", params.to_prm())?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParamSet {
+    int: Vec<(GRBIntParam, i32)>,
+    dbl: Vec<(GRBDblParam, f64)>,
+    str: Vec<(GRBStrParam, String)>,
+}
+
+fn upsert<P: PartialEq, V>(entries: &mut Vec<(P, V)>, param: P, value: V) {
+    match entries.iter_mut().find(|(p, _)| *p == param) {
+        Some(entry) => entry.1 = value,
+        None => entries.push((param, value)),
+    }
+}
+
+fn lookup<P: PartialEq, V>(entries: &[(P, V)], param: P) -> Option<&V> {
+    entries.iter().find(|(p, _)| *p == param).map(|(_, v)| v)
+}
+
+// every parameter is in a list at most once, so equal lengths and matching lookups mean the lists
+// hold the same assignments, possibly in another order
+fn same_entries<P: PartialEq + Copy, V: PartialEq>(a: &[(P, V)], b: &[(P, V)]) -> bool {
+    a.len() == b.len() && a.iter().all(|(p, v)| lookup(b, *p) == Some(v))
+}
+
+/// Two sets are equal if they assign the same values to the same parameters, in any order.
+impl PartialEq for ParamSet {
+    fn eq(&self, other: &Self) -> bool {
+        same_entries(&self.int, &other.int)
+            && same_entries(&self.dbl, &other.dbl)
+            && same_entries(&self.str, &other.str)
+    }
+}
+
+impl ParamSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a parameter of any type, e.g. `params.set(GRBIntParam::THREADS, 4)`.
+    pub fn set<P: ParamSetKey>(&mut self, param: P, value: P::Value) -> &mut Self {
+        param.insert_into(self, value);
+        self
+    }
+
+    /// Add all entries of `other`, replacing the values of parameters that are in both sets.
+    pub fn extend(&mut self, other: &ParamSet) -> &mut Self {
+        for (param, value) in &other.int {
+            upsert(&mut self.int, *param, *value);
+        }
+        for (param, value) in &other.dbl {
+            upsert(&mut self.dbl, *param, *value);
+        }
+        for (param, value) in &other.str {
+            upsert(&mut self.str, *param, value.clone());
+        }
+        self
+    }
+
+    pub fn set_int(&mut self, param: GRBIntParam, value: i32) -> &mut Self {
+        upsert(&mut self.int, param, value);
+        self
+    }
+
+    pub fn set_dbl(&mut self, param: GRBDblParam, value: f64) -> &mut Self {
+        upsert(&mut self.dbl, param, value);
+        self
+    }
+
+    pub fn set_str(&mut self, param: GRBStrParam, value: &str) -> &mut Self {
+        upsert(&mut self.str, param, value.to_string());
+        self
+    }
+
+    /// Set an enumerated parameter with a typed value, e.g. `params.set_typed(Method::Barrier)`.
+    pub fn set_typed<V: IntParamValue>(&mut self, value: V) -> &mut Self {
+        self.set_int(V::PARAM, value.into())
+    }
+
+    /// Set a parameter by its name (ignoring case). The value is converted to the type of the
+    /// parameter, e.g. an integral `Dbl` is accepted for an int parameter.
+    pub fn set_by_name(&mut self, name: &str, value: ParamValue) -> Result<&mut Self, GRBError> {
+        let invalid = |value: &ParamValue| {
+            GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                format!("invalid value {} for parameter {}", value, name),
+            )
+        };
+        if let Some(param) = GRBIntParam::from_name(name) {
+            let value = match value {
+                ParamValue::Int(value) => value,
+                ParamValue::Dbl(v) if v.fract() == 0.0 && v.abs() <= i32::MAX as f64 => v as i32,
+                ParamValue::Str(ref v) => v.trim().parse().map_err(|_| invalid(&value))?,
+                ref value => return Err(invalid(value)),
+            };
+            Ok(self.set_int(param, value))
+        } else if let Some(param) = GRBDblParam::from_name(name) {
+            let value = match value {
+                ParamValue::Int(value) => value as f64,
+                ParamValue::Dbl(value) => value,
+                ParamValue::Str(ref v) => v.trim().parse().map_err(|_| invalid(&value))?,
+            };
+            Ok(self.set_dbl(param, value))
+        } else if let Some(param) = GRBStrParam::from_name(name) {
+            Ok(self.set_str(param, &value.to_string()))
+        } else {
+            Err(GRBError::new(
+                ffi::GRB_ERROR_UNKNOWN_PARAMETER,
+                format!("unknown parameter {}", name),
+            ))
+        }
+    }
+
+    pub fn get_int(&self, param: GRBIntParam) -> Option<i32> {
+        lookup(&self.int, param).copied()
+    }
+
+    pub fn get_dbl(&self, param: GRBDblParam) -> Option<f64> {
+        lookup(&self.dbl, param).copied()
+    }
+
+    pub fn get_str(&self, param: GRBStrParam) -> Option<&str> {
+        lookup(&self.str, param).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.int.len() + self.dbl.len() + self.str.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the parameter names and values, int parameters first, then double and string
+    /// parameters.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, ParamValue)> + '_ {
+        let int = self
+            .int
+            .iter()
+            .map(|(p, v)| (p.name(), ParamValue::Int(*v)));
+        let dbl = self
+            .dbl
+            .iter()
+            .map(|(p, v)| (p.name(), ParamValue::Dbl(*v)));
+        let str = self
+            .str
+            .iter()
+            .map(|(p, v)| (p.name(), ParamValue::Str(v.clone())));
+        int.chain(dbl).chain(str)
+    }
+
+    /// Set all parameters in the set, stopping at the first error.
+    pub fn apply(&self, params: &mut GRBParams) -> Result<(), GRBError> {
+        for (param, value) in &self.int {
+            params.set(*param, *value)?;
+        }
+        for (param, value) in &self.dbl {
+            params.set(*param, *value)?;
+        }
+        for (param, value) in &self.str {
+            params.set(*param, value.clone())?;
+        }
+        Ok(())
+    }
+
+    pub fn apply_to_env(&self, env: &mut GRBenv) -> Result<(), GRBError> {
+        self.apply(&mut env.params())
+    }
+
+    pub fn apply_to_model(&self, model: &mut GRBModel) -> Result<(), GRBError> {
+        self.apply(&mut model.params())
+    }
+
+    /// The entries of this set whose value differs from the default value of the parameter.
+    pub fn non_default(&self, params: &GRBParams) -> Result<ParamSet, GRBError> {
+        let mut diff = ParamSet::new();
+        for (param, value) in &self.int {
+            if params.info(*param)?.default != *value {
+                diff.set_int(*param, *value);
+            }
+        }
+        for (param, value) in &self.dbl {
+            if params.info(*param)?.default != *value {
+                diff.set_dbl(*param, *value);
+            }
+        }
+        for (param, value) in &self.str {
+            if params.info(*param)?.default != *value {
+                diff.set_str(*param, value);
+            }
+        }
+        Ok(diff)
+    }
+
+    /// Collect all parameters whose current value differs from their default.
+    ///
+    /// Parameters that are not supported by the installed Gurobi version are skipped.
+    pub fn from_params(params: &GRBParams) -> ParamSet {
+        let mut set = ParamSet::new();
+        for param in GRBIntParam::ALL {
+            if let Some(info) = params.info(param).ok().filter(|i| i.current != i.default) {
+                set.set_int(param, info.current);
+            }
+        }
+        for param in GRBDblParam::ALL {
+            if let Some(info) = params.info(param).ok().filter(|i| i.current != i.default) {
+                set.set_dbl(param, info.current);
+            }
+        }
+        for param in GRBStrParam::ALL {
+            if let Some(info) = params.info(param).ok().filter(|i| i.current != i.default) {
+                set.set_str(param, &info.current);
+            }
+        }
+        set
+    }
+
+    /// Collect the parameters whose current value differs from `baseline`, a snapshot taken
+    /// earlier with `from_params`. Parameters that went back to their default are included too.
+    pub(crate) fn changed_since(params: &GRBParams, baseline: &ParamSet) -> ParamSet {
+        let current = ParamSet::from_params(params);
+        let mut diff = ParamSet::new();
+        for (param, value) in &current.int {
+            if lookup(&baseline.int, *param) != Some(value) {
+                diff.set_int(*param, *value);
+            }
+        }
+        for (param, value) in &current.dbl {
+            if lookup(&baseline.dbl, *param) != Some(value) {
+                diff.set_dbl(*param, *value);
+            }
+        }
+        for (param, value) in &current.str {
+            if lookup(&baseline.str, *param) != Some(value) {
+                diff.set_str(*param, value);
+            }
+        }
+        let removed = baseline
+            .int
+            .iter()
+            .filter(|(p, _)| lookup(&current.int, *p).is_none());
+        for (param, _) in removed {
+            if let Ok(info) = params.info(*param) {
+                diff.set_int(*param, info.default);
+            }
+        }
+        let removed = baseline
+            .dbl
+            .iter()
+            .filter(|(p, _)| lookup(&current.dbl, *p).is_none());
+        for (param, _) in removed {
+            if let Ok(info) = params.info(*param) {
+                diff.set_dbl(*param, info.default);
+            }
+        }
+        let removed = baseline
+            .str
+            .iter()
+            .filter(|(p, _)| lookup(&current.str, *p).is_none());
+        for (param, _) in removed {
+            if let Ok(info) = params.info(*param) {
+                diff.set_str(*param, &info.default);
+            }
+        }
+        diff
+    }
+
+    /// Parse the contents of a `.prm` file: one `Name value` pair per line. A `#` at the start of
+    /// a line or of a word starts a comment, inside a value (e.g. `LogFile run#1.log`) it is kept.
+    pub fn from_prm(text: &str) -> Result<ParamSet, GRBError> {
+        let mut set = ParamSet::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            // string parameters may be set to the empty string
+            let (name, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            set.set_by_name(name, ParamValue::Str(value.trim().to_string()))
+                .map_err(|e| {
+                    GRBError::new(e.code, format!("line {}: {}", number + 1, e.message))
+                })?;
+        }
+        Ok(set)
+    }
+
+    /// Serialize the set in the `.prm` format read by `from_prm` and `GRBenv::read_params`.
+    pub fn to_prm(&self) -> String {
+        let mut text = String::from("# Parameter settings\n");
+        for (name, value) in self.iter() {
+            text.push_str(&format!("{}  {}\n", name, value));
+        }
+        text
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut previous = None;
+    for (i, c) in line.char_indices() {
+        if c == '#' && previous.is_none_or(char::is_whitespace) {
+            return &line[..i];
+        }
+        previous = Some(c);
+    }
+    line
+}
+
+// serialized as a map from the parameter name to its value, e.g. `{"Method": 2, "TimeLimit": 60.0}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for ParamSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (name, value) in self.iter() {
+            map.serialize_entry(name, &value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ParamSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ParamSetVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ParamSetVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ParamSetVisitor {
+    type Value = ParamSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map from gurobi parameter names to values")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<ParamSet, A::Error> {
+        let mut set = ParamSet::new();
+        while let Some((name, value)) = map.next_entry::<String, ParamValue>()? {
+            set.set_by_name(&name, value)
+                .map_err(serde::de::Error::custom)?;
+        }
+        Ok(set)
+    }
+}
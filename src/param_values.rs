@@ -0,0 +1,212 @@
+// Typed values for parameters that only accept a fixed set of values, e.g. `Method::Barrier`
+// instead of `GRBIntParam::METHOD` with `GRB_METHOD_BARRIER`.
+//
+// The typed setters (`set_typed`) are a convenience on top of the raw `i32` path, which stays
+// available for values this crate does not know about yet.
+
+use crate::{
+    env::{GRBParams, GRBenv},
+    error::GRBError,
+    ffi,
+    model::GRBModel,
+    parameters::GRBIntParam,
+};
+
+/// A typed value of an integer parameter. `PARAM` is the parameter that `set_typed` and
+/// `get_typed` use.
+pub trait IntParamValue: Copy + Into<i32> + TryFrom<i32, Error = GRBError> {
+    const PARAM: GRBIntParam;
+}
+
+macro_rules! int_param_value {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $param:ident {
+            $($(#[$vmeta:meta])* $variant:ident = $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+        }
+
+        impl From<$name> for i32 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = GRBError;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                $(if value == $value {
+                    return Ok($name::$variant);
+                })*
+                Err(GRBError::new(
+                    ffi::GRB_ERROR_VALUE_OUT_OF_RANGE,
+                    format!("{} is not a valid value for {}", value, stringify!($param)),
+                ))
+            }
+        }
+
+        impl IntParamValue for $name {
+            const PARAM: GRBIntParam = GRBIntParam::$param;
+        }
+    };
+}
+
+int_param_value! {
+    /// Algorithm used to solve continuous models or the root relaxation of MIPs.
+    Method => METHOD {
+        Auto = ffi::GRB_METHOD_AUTO,
+        PrimalSimplex = ffi::GRB_METHOD_PRIMAL,
+        DualSimplex = ffi::GRB_METHOD_DUAL,
+        Barrier = ffi::GRB_METHOD_BARRIER,
+        Concurrent = ffi::GRB_METHOD_CONCURRENT,
+        DeterministicConcurrent = ffi::GRB_METHOD_DETERMINISTIC_CONCURRENT,
+        DeterministicConcurrentSimplex = ffi::GRB_METHOD_DETERMINISTIC_CONCURRENT_SIMPLEX,
+    }
+}
+
+int_param_value! {
+    /// Presolve level.
+    Presolve => PRESOLVE {
+        Auto = ffi::GRB_PRESOLVE_AUTO,
+        Off = ffi::GRB_PRESOLVE_OFF,
+        Conservative = ffi::GRB_PRESOLVE_CONSERVATIVE,
+        Aggressive = ffi::GRB_PRESOLVE_AGGRESSIVE,
+    }
+}
+
+int_param_value! {
+    /// High-level solution strategy of the MIP solver.
+    MIPFocus => MIPFOCUS {
+        Balanced = ffi::GRB_MIPFOCUS_BALANCED,
+        Feasibility = ffi::GRB_MIPFOCUS_FEASIBILITY,
+        Optimality = ffi::GRB_MIPFOCUS_OPTIMALITY,
+        BestBound = ffi::GRB_MIPFOCUS_BESTBOUND,
+    }
+}
+
+int_param_value! {
+    /// How much care to take with numerical issues, higher levels are slower.
+    NumericFocus => NUMERICFOCUS {
+        Auto = 0,
+        Low = 1,
+        Medium = 2,
+        High = 3,
+    }
+}
+
+int_param_value! {
+    /// Crossover strategy used to turn the barrier solution into a basic solution.
+    Crossover => CROSSOVER {
+        Auto = -1,
+        Off = 0,
+        /// Push dual variables first, finish with primal simplex
+        DualFirstPrimalFinish = 1,
+        /// Push dual variables first, finish with dual simplex
+        DualFirstDualFinish = 2,
+        /// Push primal variables first, finish with primal simplex
+        PrimalFirstPrimalFinish = 3,
+        /// Push primal variables first, finish with dual simplex
+        PrimalFirstDualFinish = 4,
+    }
+}
+
+int_param_value! {
+    /// Global cut aggressiveness. The individual cut parameters (e.g. `GRBIntParam::COVERCUTS`)
+    /// accept the same values through the raw path: `model.set(GRBIntParam::COVERCUTS, Cuts::Off.into())`.
+    Cuts => CUTS {
+        Auto = ffi::GRB_CUTS_AUTO,
+        Off = ffi::GRB_CUTS_OFF,
+        Conservative = ffi::GRB_CUTS_CONSERVATIVE,
+        Aggressive = ffi::GRB_CUTS_AGGRESSIVE,
+        VeryAggressive = ffi::GRB_CUTS_VERYAGGRESSIVE,
+    }
+}
+
+int_param_value! {
+    /// Branch variable selection strategy.
+    VarBranch => VARBRANCH {
+        Auto = ffi::GRB_VARBRANCH_AUTO,
+        PseudoReduced = ffi::GRB_VARBRANCH_PSEUDO_REDUCED,
+        PseudoShadow = ffi::GRB_VARBRANCH_PSEUDO_SHADOW,
+        MaxInfeasibility = ffi::GRB_VARBRANCH_MAX_INFEAS,
+        StrongBranching = ffi::GRB_VARBRANCH_STRONG,
+    }
+}
+
+int_param_value! {
+    /// How to handle non-convex quadratic objectives and constraints.
+    NonConvex => NONCONVEX {
+        Auto = -1,
+        /// Error if the original model is non-convex and cannot be transformed
+        RejectOriginal = 0,
+        /// Error if the presolved model is non-convex
+        RejectPresolved = 1,
+        /// Solve non-convex models to global optimality
+        Global = 2,
+    }
+}
+
+int_param_value! {
+    /// What the solution pool collects.
+    PoolSearchMode => POOLSEARCHMODE {
+        /// Only the solutions found along the way
+        Default = 0,
+        /// Search for additional solutions, without guarantees about their quality
+        Additional = 1,
+        /// Search for the `PoolSolutions` best solutions
+        Best = 2,
+    }
+}
+
+int_param_value! {
+    /// When new variables and constraints can be referenced.
+    UpdateMode => UPDATEMODE {
+        /// New elements can only be used after an update
+        Explicit = 0,
+        /// New elements can be used right away, updates are applied lazily
+        Lazy = 1,
+    }
+}
+
+impl GRBenv {
+    /// Set an enumerated parameter with a typed value, e.g. `env.set_typed(Method::Barrier)`.
+    pub fn set_typed<V: IntParamValue>(&mut self, value: V) -> Result<(), GRBError> {
+        self.set(V::PARAM, value.into())
+    }
+
+    /// Get an enumerated parameter as a typed value, e.g. `env.get_typed::<Method>()`.
+    pub fn get_typed<V: IntParamValue>(&self) -> Result<V, GRBError> {
+        V::try_from(self.get(V::PARAM)?)
+    }
+}
+
+impl GRBModel {
+    /// Set an enumerated parameter of this model with a typed value, e.g.
+    /// `model.set_typed(Method::Barrier)`.
+    pub fn set_typed<V: IntParamValue>(&mut self, value: V) -> Result<(), GRBError> {
+        self.params().set(V::PARAM, value.into())
+    }
+
+    /// Get an enumerated parameter of this model as a typed value.
+    pub fn get_typed<V: IntParamValue>(&self) -> Result<V, GRBError> {
+        V::try_from(self.get_param(V::PARAM)?)
+    }
+}
+
+impl GRBParams<'_> {
+    pub fn set_typed<V: IntParamValue>(&mut self, value: V) -> Result<(), GRBError> {
+        self.set(V::PARAM, value.into())
+    }
+
+    pub fn get_typed<V: IntParamValue>(&self) -> Result<V, GRBError> {
+        V::try_from(self.get(V::PARAM)?)
+    }
+}
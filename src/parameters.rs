@@ -1,8 +1,74 @@
-use crate::{ffi, model::EnvSetter};
+use crate::{
+    env::get_env_error,
+    error::GRBError,
+    ffi,
+    model::{EnvGetter, EnvSetter},
+};
 use std::ffi::{CStr, CString};
+use std::str::FromStr;
+
+/// Current, minimum, maximum and default value of a parameter. String parameters have no minimum
+/// or maximum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamInfo<T> {
+    pub current: T,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    pub default: T,
+}
+
+/// Read a string written by gurobi into a buffer of `GRB_MAX_STRLEN` bytes.
+fn string_from_buffer(buffer: &[std::ffi::c_char]) -> String {
+    unsafe { CStr::from_ptr(buffer.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Implement `name`, `from_name` and `FromStr` for a parameter enum, based on its `ALL` array and
+/// the gurobi name of each parameter.
+macro_rules! param_names {
+    ($(#[$doc:meta])* $param:ident) => {
+        impl $param {
+            /// The name of the parameter as used by gurobi, e.g. in `.prm` files.
+            pub fn name(self) -> &'static str {
+                let name: &CStr = self.into();
+                name.to_str().unwrap()
+            }
+
+            /// Look up a parameter by its name, ignoring case.
+            pub(crate) fn from_name(name: &str) -> Option<Self> {
+                Self::ALL
+                    .into_iter()
+                    .find(|param| param.name().eq_ignore_ascii_case(name))
+            }
+        }
+
+        $(#[$doc])*
+        impl FromStr for $param {
+            type Err = GRBError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $param::from_name(s).ok_or_else(|| {
+                    GRBError::new(
+                        ffi::GRB_ERROR_UNKNOWN_PARAMETER,
+                        format!("unknown parameter {}", s),
+                    )
+                })
+            }
+        }
+    };
+}
+
+/// The names of all known parameters, e.g. for shell completion.
+pub fn param_names() -> impl Iterator<Item = &'static str> {
+    let int = GRBIntParam::ALL.into_iter().map(GRBIntParam::name);
+    let dbl = GRBDblParam::ALL.into_iter().map(GRBDblParam::name);
+    let str = GRBStrParam::ALL.into_iter().map(GRBStrParam::name);
+    int.chain(dbl).chain(str)
+}
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GRBIntParam {
     INHERITPARAMS,
     FUNCNONLINEAR,
@@ -143,6 +209,40 @@ impl EnvSetter for GRBIntParam {
     }
 }
 
+impl EnvGetter for GRBIntParam {
+    type Value = i32;
+
+    fn get(&self, env: *mut ffi::GRBenv) -> Result<Self::Value, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let mut value = 0;
+        let error = unsafe { ffi::GRBgetintparam(env, param_name.as_ptr(), &mut value) };
+        get_env_error(env, error)?;
+        Ok(value)
+    }
+
+    fn info(&self, env: *mut ffi::GRBenv) -> Result<ParamInfo<Self::Value>, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let (mut current, mut min, mut max, mut default) = (0, 0, 0, 0);
+        let error = unsafe {
+            ffi::GRBgetintparaminfo(
+                env,
+                param_name.as_ptr(),
+                &mut current,
+                &mut min,
+                &mut max,
+                &mut default,
+            )
+        };
+        get_env_error(env, error)?;
+        Ok(ParamInfo {
+            current,
+            min: Some(min),
+            max: Some(max),
+            default,
+        })
+    }
+}
+
 impl From<GRBIntParam> for &'static CStr {
     fn from(value: GRBIntParam) -> &'static CStr {
         match value {
@@ -278,8 +378,148 @@ impl From<GRBIntParam> for &'static CStr {
     }
 }
 
+impl GRBIntParam {
+    /// All parameters of this type.
+    pub const ALL: [GRBIntParam; 128] = [
+        GRBIntParam::INHERITPARAMS,
+        GRBIntParam::FUNCNONLINEAR,
+        GRBIntParam::FUNCPIECES,
+        GRBIntParam::PARTITIONPLACE,
+        GRBIntParam::STARTNUMBER,
+        GRBIntParam::IGNORENAMES,
+        GRBIntParam::POOLSEARCHMODE,
+        GRBIntParam::POOLSOLUTIONS,
+        GRBIntParam::SCENARIONUMBER,
+        GRBIntParam::MULTIOBJPRE,
+        GRBIntParam::MULTIOBJMETHOD,
+        GRBIntParam::OBJPASSNUMBER,
+        GRBIntParam::OBJNUMBER,
+        GRBIntParam::UPDATEMODE,
+        GRBIntParam::TUNEDYNAMICJOBS,
+        GRBIntParam::TUNEMETRIC,
+        GRBIntParam::TUNEJOBS,
+        GRBIntParam::TUNEOUTPUT,
+        GRBIntParam::TUNETRIALS,
+        GRBIntParam::TUNECRITERION,
+        GRBIntParam::TUNERESULTS,
+        GRBIntParam::THREADLIMIT,
+        GRBIntParam::THREADS,
+        GRBIntParam::SOLUTIONTARGET,
+        GRBIntParam::SEED,
+        GRBIntParam::RECORD,
+        GRBIntParam::QCPDUAL,
+        GRBIntParam::PREMIQCPFORM,
+        GRBIntParam::PRESPARSIFY,
+        GRBIntParam::PRESOS2ENCODING,
+        GRBIntParam::PRESOS1ENCODING,
+        GRBIntParam::PRESOLVE,
+        GRBIntParam::PREQLINEARIZE,
+        GRBIntParam::PREPASSES,
+        GRBIntParam::PREDUAL,
+        GRBIntParam::PREDEPROW,
+        GRBIntParam::PRECRUSH,
+        GRBIntParam::OUTPUTFLAG,
+        GRBIntParam::NUMERICFOCUS,
+        GRBIntParam::NONCONVEX,
+        GRBIntParam::MIQCPMETHOD,
+        GRBIntParam::LOGTOCONSOLE,
+        GRBIntParam::LAZYCONSTRAINTS,
+        GRBIntParam::JSONSOLDETAIL,
+        GRBIntParam::INFUNBDINFO,
+        GRBIntParam::IISMETHOD,
+        GRBIntParam::DUALREDUCTIONS,
+        GRBIntParam::DISTRIBUTEDMIPJOBS,
+        GRBIntParam::DISPLAYINTERVAL,
+        GRBIntParam::CONCURRENTJOBS,
+        GRBIntParam::CONCURRENTMIP,
+        GRBIntParam::AGGFILL,
+        GRBIntParam::AGGREGATE,
+        GRBIntParam::OPTIMALITYTARGET,
+        GRBIntParam::LICENSEID,
+        GRBIntParam::WLSTOKENDURATION,
+        GRBIntParam::CSCLIENTLOG,
+        GRBIntParam::CSBATCHMODE,
+        GRBIntParam::TSPORT,
+        GRBIntParam::CSTLSINSECURE,
+        GRBIntParam::CSIDLETIMEOUT,
+        GRBIntParam::CSPRIORITY,
+        GRBIntParam::SERVERTIMEOUT,
+        GRBIntParam::GOMORYPASSES,
+        GRBIntParam::CUTPASSES,
+        GRBIntParam::CUTAGGPASSES,
+        GRBIntParam::MASTERKNAPSACKCUTS,
+        GRBIntParam::DUALIMPLIEDCUTS,
+        GRBIntParam::MIXINGCUTS,
+        GRBIntParam::LIFTPROJECTCUTS,
+        GRBIntParam::PSDCUTS,
+        GRBIntParam::BQPCUTS,
+        GRBIntParam::RELAXLIFTCUTS,
+        GRBIntParam::RLTCUTS,
+        GRBIntParam::INFPROOFCUTS,
+        GRBIntParam::SUBMIPCUTS,
+        GRBIntParam::NETWORKCUTS,
+        GRBIntParam::ZEROHALFCUTS,
+        GRBIntParam::MODKCUTS,
+        GRBIntParam::STRONGCGCUTS,
+        GRBIntParam::MIRCUTS,
+        GRBIntParam::MIPSEPCUTS,
+        GRBIntParam::PROJIMPLIEDCUTS,
+        GRBIntParam::IMPLIEDCUTS,
+        GRBIntParam::GUBCOVERCUTS,
+        GRBIntParam::FLOWPATHCUTS,
+        GRBIntParam::FLOWCOVERCUTS,
+        GRBIntParam::COVERCUTS,
+        GRBIntParam::CLIQUECUTS,
+        GRBIntParam::CUTS,
+        GRBIntParam::ZEROOBJNODES,
+        GRBIntParam::SOLUTIONNUMBER,
+        GRBIntParam::VARBRANCH,
+        GRBIntParam::SYMMETRY,
+        GRBIntParam::SUBMIPNODES,
+        GRBIntParam::STARTNODELIMIT,
+        GRBIntParam::RINS,
+        GRBIntParam::PUMPPASSES,
+        GRBIntParam::OBBT,
+        GRBIntParam::NORELHEURSOLUTIONS,
+        GRBIntParam::NODEMETHOD,
+        GRBIntParam::NLPHEUR,
+        GRBIntParam::MIPFOCUS,
+        GRBIntParam::MINRELNODES,
+        GRBIntParam::INTEGRALITYFOCUS,
+        GRBIntParam::FIXVARSININDICATORS,
+        GRBIntParam::DISCONNECTED,
+        GRBIntParam::DEGENMOVES,
+        GRBIntParam::BRANCHDIR,
+        GRBIntParam::PDHGGPU,
+        GRBIntParam::CROSSOVERBASIS,
+        GRBIntParam::CROSSOVER,
+        GRBIntParam::BARORDER,
+        GRBIntParam::BARHOMOGENEOUS,
+        GRBIntParam::BARCORRECTORS,
+        GRBIntParam::NETWORKALG,
+        GRBIntParam::LPWARMSTART,
+        GRBIntParam::SIFTMETHOD,
+        GRBIntParam::SIFTING,
+        GRBIntParam::NORMADJUST,
+        GRBIntParam::QUAD,
+        GRBIntParam::SIMPLEXPRICING,
+        GRBIntParam::SCALEFLAG,
+        GRBIntParam::CONCURRENTMETHOD,
+        GRBIntParam::METHOD,
+        GRBIntParam::NLBARITERLIMIT,
+        GRBIntParam::SOLUTIONLIMIT,
+        GRBIntParam::BARITERLIMIT,
+    ];
+}
+
+param_names!(
+    /// Parse the name of an integer parameter ignoring case, e.g.
+    /// `"mipfocus".parse::<GRBIntParam>()`. Names of double or string parameters are rejected.
+    GRBIntParam
+);
+
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GRBDblParam {
     FUNCMAXVAL,
     FUNCPIECERATIO,
@@ -344,6 +584,40 @@ impl EnvSetter for GRBDblParam {
     }
 }
 
+impl EnvGetter for GRBDblParam {
+    type Value = f64;
+
+    fn get(&self, env: *mut ffi::GRBenv) -> Result<Self::Value, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let mut value = 0.0;
+        let error = unsafe { ffi::GRBgetdblparam(env, param_name.as_ptr(), &mut value) };
+        get_env_error(env, error)?;
+        Ok(value)
+    }
+
+    fn info(&self, env: *mut ffi::GRBenv) -> Result<ParamInfo<Self::Value>, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let (mut current, mut min, mut max, mut default) = (0.0, 0.0, 0.0, 0.0);
+        let error = unsafe {
+            ffi::GRBgetdblparaminfo(
+                env,
+                param_name.as_ptr(),
+                &mut current,
+                &mut min,
+                &mut max,
+                &mut default,
+            )
+        };
+        get_env_error(env, error)?;
+        Ok(ParamInfo {
+            current,
+            min: Some(min),
+            max: Some(max),
+            default,
+        })
+    }
+}
+
 impl From<GRBDblParam> for &'static CStr {
     fn from(value: GRBDblParam) -> &'static CStr {
         match value {
@@ -403,8 +677,72 @@ impl From<GRBDblParam> for &'static CStr {
     }
 }
 
+impl GRBDblParam {
+    /// All parameters of this type.
+    pub const ALL: [GRBDblParam; 52] = [
+        GRBDblParam::FUNCMAXVAL,
+        GRBDblParam::FUNCPIECERATIO,
+        GRBDblParam::FUNCPIECEERROR,
+        GRBDblParam::FUNCPIECELENGTH,
+        GRBDblParam::POOLGAPABS,
+        GRBDblParam::POOLGAP,
+        GRBDblParam::TUNETARGETTIME,
+        GRBDblParam::TUNETARGETMIPGAP,
+        GRBDblParam::TUNECLEANUP,
+        GRBDblParam::TUNETIMELIMIT,
+        GRBDblParam::PRESOS2BIGM,
+        GRBDblParam::PRESOS1BIGM,
+        GRBDblParam::FEASRELAXBIGM,
+        GRBDblParam::WLSTOKENREFRESH,
+        GRBDblParam::CSQUEUETIMEOUT,
+        GRBDblParam::STARTWORKLIMIT,
+        GRBDblParam::STARTTIMELIMIT,
+        GRBDblParam::NORELHEURWORK,
+        GRBDblParam::NORELHEURTIME,
+        GRBDblParam::NODEFILESTART,
+        GRBDblParam::IMPROVESTARTNODES,
+        GRBDblParam::IMPROVESTARTWORK,
+        GRBDblParam::IMPROVESTARTTIME,
+        GRBDblParam::IMPROVESTARTGAP,
+        GRBDblParam::HEURISTICS,
+        GRBDblParam::PDHGCONVTOL,
+        GRBDblParam::PDHGABSTOL,
+        GRBDblParam::PDHGRELTOL,
+        GRBDblParam::PDHGITERLIMIT,
+        GRBDblParam::BARQCPCONVTOL,
+        GRBDblParam::BARCONVTOL,
+        GRBDblParam::OBJSCALE,
+        GRBDblParam::PERTURBVALUE,
+        GRBDblParam::NLBARCFEASTOL,
+        GRBDblParam::NLBARDFEASTOL,
+        GRBDblParam::NLBARPFEASTOL,
+        GRBDblParam::PSDTOL,
+        GRBDblParam::OPTIMALITYTOL,
+        GRBDblParam::MIPGAPABS,
+        GRBDblParam::MIPGAP,
+        GRBDblParam::MARKOWITZTOL,
+        GRBDblParam::INTFEASTOL,
+        GRBDblParam::FEASIBILITYTOL,
+        GRBDblParam::BESTBDSTOP,
+        GRBDblParam::BESTOBJSTOP,
+        GRBDblParam::SOFTMEMLIMIT,
+        GRBDblParam::MEMLIMIT,
+        GRBDblParam::WORKLIMIT,
+        GRBDblParam::TIMELIMIT,
+        GRBDblParam::NODELIMIT,
+        GRBDblParam::ITERATIONLIMIT,
+        GRBDblParam::CUTOFF,
+    ];
+}
+
+param_names!(
+    /// Parse the name of a double parameter ignoring case, e.g.
+    /// `"timelimit".parse::<GRBDblParam>()`.
+    GRBDblParam
+);
+
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GRBStrParam {
     JOBID,
     DUMMY,
@@ -447,6 +785,39 @@ impl EnvSetter for GRBStrParam {
     }
 }
 
+impl EnvGetter for GRBStrParam {
+    type Value = String;
+
+    fn get(&self, env: *mut ffi::GRBenv) -> Result<Self::Value, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let mut value = vec![0 as std::ffi::c_char; ffi::GRB_MAX_STRLEN as usize];
+        let error = unsafe { ffi::GRBgetstrparam(env, param_name.as_ptr(), value.as_mut_ptr()) };
+        get_env_error(env, error)?;
+        Ok(string_from_buffer(&value))
+    }
+
+    fn info(&self, env: *mut ffi::GRBenv) -> Result<ParamInfo<Self::Value>, GRBError> {
+        let param_name: &CStr = (*self).into();
+        let mut current = vec![0 as std::ffi::c_char; ffi::GRB_MAX_STRLEN as usize];
+        let mut default = vec![0 as std::ffi::c_char; ffi::GRB_MAX_STRLEN as usize];
+        let error = unsafe {
+            ffi::GRBgetstrparaminfo(
+                env,
+                param_name.as_ptr(),
+                current.as_mut_ptr(),
+                default.as_mut_ptr(),
+            )
+        };
+        get_env_error(env, error)?;
+        Ok(ParamInfo {
+            current: string_from_buffer(&current),
+            min: None,
+            max: None,
+            default: string_from_buffer(&default),
+        })
+    }
+}
+
 impl From<GRBStrParam> for &'static CStr {
     fn from(value: GRBStrParam) -> &'static CStr {
         match value {
@@ -481,4 +852,43 @@ impl From<GRBStrParam> for &'static CStr {
         }
     }
 }
+
+impl GRBStrParam {
+    /// All parameters of this type.
+    pub const ALL: [GRBStrParam; 28] = [
+        GRBStrParam::JOBID,
+        GRBStrParam::DUMMY,
+        GRBStrParam::RESULTFILE,
+        GRBStrParam::LOGFILE,
+        GRBStrParam::WLSCONFIG,
+        GRBStrParam::WLSPROXY,
+        GRBStrParam::WLSTOKEN,
+        GRBStrParam::WLSSECRET,
+        GRBStrParam::WLSACCESSID,
+        GRBStrParam::CSAPPNAME,
+        GRBStrParam::USERNAME,
+        GRBStrParam::CSAPISECRET,
+        GRBStrParam::CSAPIACCESSID,
+        GRBStrParam::CSAUTHTOKEN,
+        GRBStrParam::CSMANAGER,
+        GRBStrParam::CLOUDHOST,
+        GRBStrParam::CLOUDPOOL,
+        GRBStrParam::CLOUDSECRETKEY,
+        GRBStrParam::CLOUDACCESSID,
+        GRBStrParam::CSGROUP,
+        GRBStrParam::CSROUTER,
+        GRBStrParam::SERVERPASSWORD,
+        GRBStrParam::TOKENSERVER,
+        GRBStrParam::COMPUTESERVER,
+        GRBStrParam::WORKERPASSWORD,
+        GRBStrParam::WORKERPOOL,
+        GRBStrParam::SOLFILES,
+        GRBStrParam::NODEFILEDIR,
+    ];
+}
+
+param_names!(
+    /// Parse the name of a string parameter ignoring case, e.g. `"LogFile".parse::<GRBStrParam>()`.
+    GRBStrParam
+);
 // TODO: Add tests!
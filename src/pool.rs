@@ -0,0 +1,121 @@
+// Access to the solution pool: every solution found during a MIP solve, selected with the
+// `SolutionNumber` parameter and read with the `PoolNX` and `PoolNObjVal` attributes.
+
+use std::ops::Index;
+
+use crate::{
+    attributes::{GRBDblAttr, GRBIntAttr},
+    error::GRBError,
+    ffi,
+    model::GRBModel,
+    modeling::IsModelingObject,
+    param_values::PoolSearchMode,
+    parameters::GRBIntParam,
+    var::GRBVar,
+};
+
+/// A solution from the solution pool of a model.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolSolution {
+    /// Position in the pool, 0 is the best solution
+    pub number: usize,
+    pub obj_val: f64,
+    /// Value of every variable
+    pub x: Vec<f64>,
+}
+
+impl PoolSolution {
+    pub fn value(&self, var: &GRBVar) -> f64 {
+        self.x[var.index()]
+    }
+}
+
+impl Index<&GRBVar> for PoolSolution {
+    type Output = f64;
+
+    fn index(&self, var: &GRBVar) -> &f64 {
+        &self.x[var.index()]
+    }
+}
+
+/// Iterator over the solution pool, created with `GRBModel::solution_pool`. Solutions are ordered
+/// from best to worst.
+///
+/// Reading a solution changes the `SolutionNumber` parameter of the model, it is reset to 0 when
+/// the iterator is dropped.
+pub struct SolutionPool<'a> {
+    model: &'a mut GRBModel,
+    next: usize,
+    count: usize,
+}
+
+impl SolutionPool<'_> {
+    fn read(&mut self, number: usize) -> Result<PoolSolution, GRBError> {
+        self.model
+            .set_int_param(GRBIntParam::SOLUTIONNUMBER, param_value(number)?)?;
+        Ok(PoolSolution {
+            number,
+            obj_val: self.model.get(GRBDblAttr::POOLNOBJVAL)?,
+            x: self.model.get_all(GRBDblAttr::POOLNX)?,
+        })
+    }
+}
+
+fn param_value(value: usize) -> Result<i32, GRBError> {
+    i32::try_from(value).map_err(|_| {
+        GRBError::new(
+            ffi::GRB_ERROR_VALUE_OUT_OF_RANGE,
+            format!("{} does not fit an integer parameter", value),
+        )
+    })
+}
+
+impl Iterator for SolutionPool<'_> {
+    type Item = Result<PoolSolution, GRBError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count {
+            return None;
+        }
+        let result = self.read(self.next);
+        self.next += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for SolutionPool<'_> {}
+
+impl Drop for SolutionPool<'_> {
+    fn drop(&mut self) {
+        let _ = self.model.set_int_param(GRBIntParam::SOLUTIONNUMBER, 0);
+    }
+}
+
+impl GRBModel {
+    /// Iterate over the solutions found by the last optimization, best first.
+    pub fn solution_pool(&mut self) -> Result<SolutionPool<'_>, GRBError> {
+        let count = self.get(GRBIntAttr::SOLCOUNT)? as usize;
+        Ok(SolutionPool {
+            model: self,
+            next: 0,
+            count,
+        })
+    }
+
+    /// Optimize the model while searching for the `k` best solutions (`PoolSearchMode::Best`,
+    /// `PoolSolutions = k`), and return the solutions that were found, best first.
+    ///
+    /// The pool parameters stay set on the model afterwards.
+    pub fn optimize_k_best(&mut self, k: usize) -> Result<Vec<PoolSolution>, GRBError> {
+        self.set_int_param(GRBIntParam::POOLSEARCHMODE, PoolSearchMode::Best.into())?;
+        self.set_int_param(GRBIntParam::POOLSOLUTIONS, param_value(k)?)?;
+        self.try_optimize()?;
+        self.solution_pool()?.collect()
+    }
+}
@@ -1,10 +1,32 @@
-pub use crate::attributes::{GRBCharAttr, GRBDblAttr, GRBIntAttr, GRBStrAttr};
+pub use crate::asynchronous::AsyncOptimization;
+pub use crate::attributes::{kind, GRBAttrObject, GRBCharAttr, GRBDblAttr, GRBIntAttr, GRBStrAttr};
+pub use crate::basis::{Basis, BasisStatus};
+pub use crate::batch::GRBbatch;
 pub use crate::callback::{CallbackTrait, GRBCallback, GRBCallbackContext};
 pub use crate::constr::Expr;
 pub use crate::constr::GRBConstr;
-pub use crate::env::GRBenv;
-pub use crate::model::{GRBModel, GRBModelSense};
+pub use crate::derived::DerivedModel;
+pub use crate::env::{GRBParams, GRBenv, GRBenvBuilder, ParamScope};
+pub use crate::error::GRBError;
+pub use crate::feas_relax::{FeasRelaxResult, RelaxType, Relaxed};
+pub use crate::iis::{IisForce, IisProgress, IisReport};
+pub use crate::model::{GRBModel, GRBModelSense, GRBStatus};
 pub use crate::modeling::builder::var::GRBVarBuilder;
 pub use crate::modeling::expr::{lin_expr::GRBLinExpr, GRBSense};
-pub use crate::parameters::{GRBDblParam, GRBIntParam, GRBStrParam};
+pub use crate::outcome::SolveOutcome;
+pub use crate::param_set::{ParamSet, ParamSetKey, ParamValue};
+pub use crate::param_values::{
+    Crossover, Cuts, IntParamValue, MIPFocus, Method, NonConvex, NumericFocus, PoolSearchMode,
+    Presolve, UpdateMode, VarBranch,
+};
+pub use crate::parameters::{param_names, GRBDblParam, GRBIntParam, GRBStrParam, ParamInfo};
+pub use crate::pool::{PoolSolution, SolutionPool};
+pub use crate::progress::{Progress, ProgressPhase};
+pub use crate::sensitivity::{ConstrSensitivity, SensitivityReport, VarSensitivity};
+pub use crate::solution::Solution;
+pub use crate::termination::{
+    GapAfter, ObjectiveTarget, Stall, TerminateHandle, TerminationPolicy, TerminationReason,
+    WallClock,
+};
+pub use crate::tuning::TuneResults;
 pub use crate::var::{GRBVar, GRBVarType};
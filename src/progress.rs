@@ -0,0 +1,174 @@
+// Typed progress snapshots built from the `SIMPLEX`, `BARRIER` and `MIP` callbacks, so users can
+// follow an optimization without writing a raw callback.
+
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+use crate::callback::{
+    CallbackTrait, GRBCallback, GRBCallbackCodes, GRBCallbackContext, GRB_WHAT_DOUBLE, GRB_WHAT_INT,
+};
+use crate::error::GRBError;
+use crate::ffi;
+use crate::model::GRBModel;
+use crate::outcome::SolveOutcome;
+
+/// The algorithm that produced a `Progress` snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressPhase {
+    Simplex,
+    Barrier,
+    Mip,
+}
+
+/// Snapshot of the state of a running optimization.
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    pub phase: ProgressPhase,
+    /// Elapsed solver time in seconds
+    pub runtime: f64,
+    /// Best objective found so far (MIP), or the current primal objective (simplex, barrier)
+    pub incumbent: Option<f64>,
+    /// Best bound (MIP), or the current dual objective (barrier)
+    pub bound: Option<f64>,
+    /// Relative gap between `incumbent` and `bound`, computed as Gurobi does for `MIPGap`
+    pub gap: Option<f64>,
+    /// Explored branch-and-bound nodes
+    pub node_count: f64,
+    /// Unexplored branch-and-bound nodes
+    pub open_node_count: f64,
+    /// Simplex or barrier iterations
+    pub iter_count: f64,
+    /// Number of feasible solutions found
+    pub sol_count: i32,
+}
+
+/// Gurobi reports missing incumbents/bounds as +/- `GRB_INFINITY`
+fn finite(value: f64) -> Option<f64> {
+    (value.abs() < ffi::GRB_INFINITY).then_some(value)
+}
+
+fn relative_gap(incumbent: Option<f64>, bound: Option<f64>) -> Option<f64> {
+    let (incumbent, bound) = (incumbent?, bound?);
+    let diff = (bound - incumbent).abs();
+    if diff == 0.0 {
+        Some(0.0)
+    } else if incumbent == 0.0 {
+        Some(f64::INFINITY)
+    } else {
+        Some(diff / incumbent.abs())
+    }
+}
+
+impl Progress {
+    /// Build a snapshot from a callback context. Returns `None` for callback codes that do not
+    /// carry progress information.
+    pub(crate) fn from_context(ctx: &mut GRBCallbackContext) -> Option<Progress> {
+        let phase = match GRBCallbackCodes::from(ctx.where_) {
+            GRBCallbackCodes::SIMPLEX => ProgressPhase::Simplex,
+            GRBCallbackCodes::BARRIER => ProgressPhase::Barrier,
+            GRBCallbackCodes::MIP => ProgressPhase::Mip,
+            _ => return None,
+        };
+        let runtime = ctx.get_info(GRB_WHAT_DOUBLE::RUNTIME).ok()?;
+        let mut progress = match phase {
+            ProgressPhase::Simplex => Progress {
+                phase,
+                runtime,
+                incumbent: finite(ctx.get_info(GRB_WHAT_DOUBLE::SPX_OBJVAL).ok()?),
+                bound: None,
+                gap: None,
+                node_count: 0.0,
+                open_node_count: 0.0,
+                iter_count: ctx.get_info(GRB_WHAT_DOUBLE::SPX_ITRCNT).ok()?,
+                sol_count: 0,
+            },
+            ProgressPhase::Barrier => Progress {
+                phase,
+                runtime,
+                incumbent: finite(ctx.get_info(GRB_WHAT_DOUBLE::BARRIER_PRIMOBJ).ok()?),
+                bound: finite(ctx.get_info(GRB_WHAT_DOUBLE::BARRIER_DUALOBJ).ok()?),
+                gap: None,
+                node_count: 0.0,
+                open_node_count: 0.0,
+                iter_count: ctx.get_info(GRB_WHAT_INT::BARRIER_ITRCNT).ok()? as f64,
+                sol_count: 0,
+            },
+            ProgressPhase::Mip => Progress {
+                phase,
+                runtime,
+                incumbent: finite(ctx.get_info(GRB_WHAT_DOUBLE::MIP_OBJBST).ok()?),
+                bound: finite(ctx.get_info(GRB_WHAT_DOUBLE::MIP_OBJBND).ok()?),
+                gap: None,
+                node_count: ctx.get_info(GRB_WHAT_DOUBLE::MIP_NODCNT).ok()?,
+                open_node_count: ctx.get_info(GRB_WHAT_DOUBLE::MIP_NODLFT).ok()?,
+                iter_count: ctx.get_info(GRB_WHAT_DOUBLE::MIP_ITRCNT).ok()?,
+                sol_count: ctx.get_info(GRB_WHAT_INT::MIP_SOLCNT).ok()?,
+            },
+        };
+        progress.gap = relative_gap(progress.incumbent, progress.bound);
+        Some(progress)
+    }
+}
+
+/// Callback that throttles progress snapshots and hands them to a closure. The last snapshot that
+/// was held back by the throttling is kept in `skipped`, so it can be emitted after the solve.
+struct ProgressCallback<'a, F: FnMut(Progress)> {
+    on_progress: &'a RefCell<F>,
+    interval: Duration,
+    last: Cell<Option<Instant>>,
+    skipped: &'a Cell<Option<Progress>>,
+}
+
+impl<F: FnMut(Progress)> CallbackTrait for ProgressCallback<'_, F> {
+    fn callback(&self, mut cb_ctx: GRBCallbackContext) {
+        let Some(progress) = Progress::from_context(&mut cb_ctx) else {
+            return;
+        };
+        let now = Instant::now();
+        if self
+            .last
+            .get()
+            .is_some_and(|last| now.duration_since(last) < self.interval)
+        {
+            self.skipped.set(Some(progress));
+            return;
+        }
+        self.last.set(Some(now));
+        self.skipped.set(None);
+        (self.on_progress.borrow_mut())(progress);
+    }
+}
+
+impl GRBModel {
+    /// Optimize the model and call `on_progress` with a `Progress` snapshot at most once every
+    /// `interval`. The last snapshot of the solve is always delivered, if it was held back by the
+    /// throttling it is delivered after the solve finished.
+    ///
+    /// This installs its own callback for the duration of the solve, a callback registered with
+    /// `set_callback` is not called and is restored afterwards. To consume the snapshots on another
+    /// thread, send them through an `std::sync::mpsc::Sender` from `on_progress`.
+    pub fn optimize_with_progress<F: FnMut(Progress)>(
+        &mut self,
+        interval: Duration,
+        on_progress: F,
+    ) -> Result<SolveOutcome, GRBError> {
+        let on_progress = RefCell::new(on_progress);
+        let skipped = Cell::new(None);
+        let mut callback = GRBCallback::new(ProgressCallback {
+            on_progress: &on_progress,
+            interval,
+            last: Cell::new(None),
+            skipped: &skipped,
+        });
+        let previous = self.callback;
+        self.set_callback(&mut callback);
+        let result = self.run_optimize();
+        // the callback is dropped at the end of this function, gurobi may not keep a pointer to it
+        self.register_callback(previous);
+        result?;
+        if let Some(progress) = skipped.take() {
+            (on_progress.borrow_mut())(progress);
+        }
+        self.solve_outcome()
+    }
+}
@@ -0,0 +1,132 @@
+// Sensitivity analysis of an optimal basic solution of an LP: the ranges over which objective
+// coefficients, bounds and right-hand sides can change without changing the optimal basis.
+
+use crate::{
+    attributes::{GRBAttrObject, GRBDblAttr, GRBIntAttr, GRBStrAttr},
+    constr::GRBConstr,
+    error::GRBError,
+    ffi,
+    model::GRBModel,
+    modeling::IsModelingObject,
+    var::GRBVar,
+};
+
+/// Sensitivity information of a single variable.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VarSensitivity {
+    pub name: String,
+    pub value: f64,
+    pub reduced_cost: f64,
+    /// Range of the objective coefficient (`SAObjLow`, `SAObjUp`)
+    pub obj_low: f64,
+    pub obj_up: f64,
+    /// Range of the lower bound (`SALBLow`, `SALBUp`)
+    pub lb_low: f64,
+    pub lb_up: f64,
+    /// Range of the upper bound (`SAUBLow`, `SAUBUp`)
+    pub ub_low: f64,
+    pub ub_up: f64,
+}
+
+/// Sensitivity information of a single linear constraint.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstrSensitivity {
+    pub name: String,
+    pub slack: f64,
+    pub dual: f64,
+    /// Range of the right-hand side (`SARHSLow`, `SARHSUp`)
+    pub rhs_low: f64,
+    pub rhs_up: f64,
+}
+
+/// Sensitivity information of every variable and linear constraint of an LP, created with
+/// `GRBModel::sensitivity`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensitivityReport {
+    pub vars: Vec<VarSensitivity>,
+    pub constrs: Vec<ConstrSensitivity>,
+}
+
+impl SensitivityReport {
+    /// The sensitivity information of a variable, `None` if the report has no variable with its
+    /// index.
+    pub fn var(&self, var: &GRBVar) -> Option<&VarSensitivity> {
+        self.vars.get(var.index())
+    }
+
+    /// The sensitivity information of a linear constraint, `None` for quadratic and general
+    /// constraints, which have none.
+    pub fn constr(&self, constr: &GRBConstr) -> Option<&ConstrSensitivity> {
+        if constr.kind() != GRBAttrObject::Constr {
+            return None;
+        }
+        self.constrs.get(constr.index())
+    }
+}
+
+impl GRBModel {
+    /// Read the sensitivity information of the optimal basis of this LP.
+    ///
+    /// Only available for continuous models solved to optimality with a basis. For a MIP, solve
+    /// it and analyze the fixed model instead: `model.fixed()?.model`.
+    pub fn sensitivity(&self) -> Result<SensitivityReport, GRBError> {
+        if self.get(GRBIntAttr::IS_MIP)? != 0 {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_NOT_FOR_MIP,
+                "sensitivity information is not available for MIPs, \
+                 use `fixed()` to analyze the fixed model of a solved MIP instead",
+            ));
+        }
+        if self.get(GRBIntAttr::SOLCOUNT)? == 0 {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_DATA_NOT_AVAILABLE,
+                "the model has no solution",
+            ));
+        }
+        let names = self.get_all(GRBStrAttr::VARNAME)?;
+        let x = self.get_all(GRBDblAttr::X)?;
+        let rc = self.get_all(GRBDblAttr::RC)?;
+        let obj_low = self.get_all(GRBDblAttr::SA_OBJLOW)?;
+        let obj_up = self.get_all(GRBDblAttr::SA_OBJUP)?;
+        let lb_low = self.get_all(GRBDblAttr::SA_LBLOW)?;
+        let lb_up = self.get_all(GRBDblAttr::SA_LBUP)?;
+        let ub_low = self.get_all(GRBDblAttr::SA_UBLOW)?;
+        let ub_up = self.get_all(GRBDblAttr::SA_UBUP)?;
+        let vars = names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| VarSensitivity {
+                name,
+                value: x[i],
+                reduced_cost: rc[i],
+                obj_low: obj_low[i],
+                obj_up: obj_up[i],
+                lb_low: lb_low[i],
+                lb_up: lb_up[i],
+                ub_low: ub_low[i],
+                ub_up: ub_up[i],
+            })
+            .collect();
+
+        let names = self.get_all(GRBStrAttr::CONSTRNAME)?;
+        let slack = self.get_all(GRBDblAttr::SLACK)?;
+        let pi = self.get_all(GRBDblAttr::PI)?;
+        let rhs_low = self.get_all(GRBDblAttr::SA_RHSLOW)?;
+        let rhs_up = self.get_all(GRBDblAttr::SA_RHSUP)?;
+        let constrs = names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| ConstrSensitivity {
+                name,
+                slack: slack[i],
+                dual: pi[i],
+                rhs_low: rhs_low[i],
+                rhs_up: rhs_up[i],
+            })
+            .collect();
+        Ok(SensitivityReport { vars, constrs })
+    }
+}
@@ -0,0 +1,150 @@
+// Snapshot of the solution of a model, detached from the model so it can be kept around after
+// the model changes or is dropped.
+
+use std::ops::Index;
+
+use crate::{
+    attributes::{GRBAttrObject, GRBDblAttr, GRBIntAttr, GRBStrAttr},
+    constr::GRBConstr,
+    error::GRBError,
+    ffi,
+    model::{GRBModel, GRBStatus},
+    modeling::IsModelingObject,
+    parameters::GRBIntParam,
+    var::GRBVar,
+};
+
+/// The solution of a model, created with `GRBModel::solution`.
+///
+/// Values are indexed by the `GRBVar` and `GRBConstr` handles of the model they were read from,
+/// e.g. `solution[&x]`, or by name with `value_by_name`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    pub status: GRBStatus,
+    pub obj_val: f64,
+    /// Best known bound on the objective, only available for MIPs
+    pub obj_bound: Option<f64>,
+    /// Value of every variable (`X`)
+    pub x: Vec<f64>,
+    /// Slack of every linear constraint
+    pub slack: Vec<f64>,
+    /// Dual value of every linear constraint (`PI`), only available for continuous models (with
+    /// `QCPDual` set if the model has quadratic constraints)
+    pub pi: Option<Vec<f64>>,
+    /// Reduced cost of every variable (`RC`), available whenever `pi` is
+    pub rc: Option<Vec<f64>>,
+    pub var_names: Vec<String>,
+    pub constr_names: Vec<String>,
+}
+
+impl Solution {
+    /// The value of `var` in this solution.
+    pub fn value(&self, var: &GRBVar) -> f64 {
+        self.x[var.index()]
+    }
+
+    /// The value of the variable named `name`, if there is one.
+    pub fn value_by_name(&self, name: &str) -> Option<f64> {
+        let index = self.var_names.iter().position(|n| n == name)?;
+        Some(self.x[index])
+    }
+
+    // position of a linear constraint in `slack` and `pi`
+    fn row(&self, constr: &GRBConstr) -> Option<usize> {
+        (constr.kind == GRBAttrObject::Constr && constr.index() < self.slack.len())
+            .then_some(constr.index())
+    }
+
+    /// The slack of a linear constraint, `None` for quadratic and general constraints.
+    pub fn slack(&self, constr: &GRBConstr) -> Option<f64> {
+        self.row(constr).map(|row| self.slack[row])
+    }
+
+    /// The dual value of a linear constraint, `None` for MIPs and for quadratic and general
+    /// constraints.
+    pub fn dual(&self, constr: &GRBConstr) -> Option<f64> {
+        let row = self.row(constr)?;
+        self.pi.as_ref().map(|pi| pi[row])
+    }
+
+    /// The reduced cost of a variable, `None` for MIPs.
+    pub fn reduced_cost(&self, var: &GRBVar) -> Option<f64> {
+        self.rc.as_ref().map(|rc| rc[var.index()])
+    }
+
+    /// Iterate over the variable names and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> + '_ {
+        self.var_names
+            .iter()
+            .map(String::as_str)
+            .zip(self.x.iter().copied())
+    }
+}
+
+impl Index<&GRBVar> for Solution {
+    type Output = f64;
+
+    fn index(&self, var: &GRBVar) -> &f64 {
+        &self.x[var.index()]
+    }
+}
+
+/// The slack of a linear constraint, e.g. `solution[&c]`. Panics for quadratic and general
+/// constraints, use `Solution::slack` to handle those.
+impl Index<&GRBConstr> for Solution {
+    type Output = f64;
+
+    fn index(&self, constr: &GRBConstr) -> &f64 {
+        match self.row(constr) {
+            Some(row) => &self.slack[row],
+            None => panic!(
+                "{:?} {} has no slack in the solution",
+                constr.kind,
+                constr.index()
+            ),
+        }
+    }
+}
+
+impl GRBModel {
+    /// Read the current solution of the model into a `Solution`.
+    ///
+    /// Returns an error if no solution is available. Duals and reduced costs are only read for
+    /// continuous models, and for models with quadratic constraints only if `QCPDual` is set.
+    pub fn solution(&self) -> Result<Solution, GRBError> {
+        if self.get(GRBIntAttr::SOLCOUNT)? == 0 {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_DATA_NOT_AVAILABLE,
+                "the model has no solution",
+            ));
+        }
+        let is_mip = self.get(GRBIntAttr::IS_MIP)? != 0;
+        let has_duals = !is_mip
+            && (self.get(GRBIntAttr::NUMQCONSTRS)? == 0
+                || self.get_param(GRBIntParam::QCPDUAL)? != 0);
+        Ok(Solution {
+            status: self.status(),
+            obj_val: self.get(GRBDblAttr::OBJVAL)?,
+            obj_bound: if is_mip {
+                Some(self.get(GRBDblAttr::OBJBOUND)?)
+            } else {
+                None
+            },
+            x: self.get_all(GRBDblAttr::X)?,
+            slack: self.get_all(GRBDblAttr::SLACK)?,
+            pi: if has_duals {
+                Some(self.get_all(GRBDblAttr::PI)?)
+            } else {
+                None
+            },
+            rc: if has_duals {
+                Some(self.get_all(GRBDblAttr::RC)?)
+            } else {
+                None
+            },
+            var_names: self.get_all(GRBStrAttr::VARNAME)?,
+            constr_names: self.get_all(GRBStrAttr::CONSTRNAME)?,
+        })
+    }
+}
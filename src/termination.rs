@@ -0,0 +1,368 @@
+// Custom termination policies, evaluated on every `MIP` callback. When a policy fires, the
+// optimization is stopped with `GRBterminate` and the reason is stored on the model.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::attributes::GRBIntAttr;
+use crate::callback::{CallbackTrait, GRBCallback, GRBCallbackCodes, GRBCallbackContext};
+use crate::error::GRBError;
+use crate::ffi;
+use crate::model::{GRBModel, GRBModelSense};
+use crate::outcome::SolveOutcome;
+use crate::progress::Progress;
+
+/// Why a `TerminationPolicy` stopped the optimization.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TerminationReason {
+    pub message: String,
+}
+
+impl TerminationReason {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// A rule that decides, based on the progress of the MIP solve, whether to stop the optimization.
+pub trait TerminationPolicy {
+    /// Called on every `MIP` callback. Return a reason to stop the optimization.
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason>;
+
+    /// Called once before the optimization starts, e.g. to read attributes of the model.
+    fn prepare(&mut self, _model: &GRBModel) {}
+
+    /// Stop only when both policies want to stop.
+    fn and<P: TerminationPolicy>(self, other: P) -> And<Self, P>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Stop when either policy wants to stop.
+    fn or<P: TerminationPolicy>(self, other: P) -> Or<Self, P>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+}
+
+/// Policy created by `TerminationPolicy::and`.
+pub struct And<A, B>(A, B);
+
+impl<A: TerminationPolicy, B: TerminationPolicy> TerminationPolicy for And<A, B> {
+    fn prepare(&mut self, model: &GRBModel) {
+        self.0.prepare(model);
+        self.1.prepare(model);
+    }
+
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason> {
+        // always evaluate both sides, stateful policies need to see every callback
+        let a = self.0.check(progress);
+        let b = self.1.check(progress);
+        match (a, b) {
+            (Some(a), Some(b)) => Some(TerminationReason::new(format!("{} and {}", a, b))),
+            _ => None,
+        }
+    }
+}
+
+/// Policy created by `TerminationPolicy::or`.
+pub struct Or<A, B>(A, B);
+
+impl<A: TerminationPolicy, B: TerminationPolicy> TerminationPolicy for Or<A, B> {
+    fn prepare(&mut self, model: &GRBModel) {
+        self.0.prepare(model);
+        self.1.prepare(model);
+    }
+
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason> {
+        // always evaluate both sides, stateful policies need to see every callback
+        let a = self.0.check(progress);
+        let b = self.1.check(progress);
+        a.or(b)
+    }
+}
+
+/// Stop once the relative gap is below `gap` and at least `after` has elapsed.
+///
+/// E.g. `GapAfter::new(0.05, Duration::from_secs(60))` stops if the gap is below 5% after 60s.
+pub struct GapAfter {
+    gap: f64,
+    after: Duration,
+}
+
+impl GapAfter {
+    pub fn new(gap: f64, after: Duration) -> Self {
+        Self { gap, after }
+    }
+}
+
+impl TerminationPolicy for GapAfter {
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason> {
+        let gap = progress.gap?;
+        if progress.runtime >= self.after.as_secs_f64() && gap <= self.gap {
+            Some(TerminationReason::new(format!(
+                "gap {:.4} below {:.4} after {:.1}s",
+                gap, self.gap, progress.runtime
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+/// Stop once the incumbent has not improved for a number of nodes or seconds.
+///
+/// Counting starts when the first incumbent is found. Changes of the incumbent smaller than the
+/// relative `tolerance` (default `1e-6`) do not count as an improvement.
+pub struct Stall {
+    nodes: Option<f64>,
+    seconds: Option<f64>,
+    tolerance: f64,
+    best: Option<f64>,
+    improved_at_node: f64,
+    improved_at_time: f64,
+}
+
+impl Stall {
+    fn new(nodes: Option<f64>, seconds: Option<f64>) -> Self {
+        Self {
+            nodes,
+            seconds,
+            tolerance: 1e-6,
+            best: None,
+            improved_at_node: 0.0,
+            improved_at_time: 0.0,
+        }
+    }
+
+    /// Stop if the incumbent has not improved for `nodes` explored nodes.
+    pub fn nodes(nodes: f64) -> Self {
+        Self::new(Some(nodes), None)
+    }
+
+    /// Stop if the incumbent has not improved for `duration`.
+    pub fn time(duration: Duration) -> Self {
+        Self::new(None, Some(duration.as_secs_f64()))
+    }
+
+    /// Only count changes of the incumbent larger than `tolerance`, relative to the incumbent, as
+    /// an improvement.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn improved(&self, incumbent: f64) -> bool {
+        match self.best {
+            Some(best) => (incumbent - best).abs() > self.tolerance * best.abs().max(1.0),
+            None => true,
+        }
+    }
+}
+
+impl TerminationPolicy for Stall {
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason> {
+        let incumbent = progress.incumbent?;
+        if self.improved(incumbent) {
+            self.best = Some(incumbent);
+            self.improved_at_node = progress.node_count;
+            self.improved_at_time = progress.runtime;
+            return None;
+        }
+        let stalled_nodes = progress.node_count - self.improved_at_node;
+        let stalled_time = progress.runtime - self.improved_at_time;
+        if self.nodes.is_some_and(|n| stalled_nodes >= n) {
+            Some(TerminationReason::new(format!(
+                "incumbent not improved for {} nodes",
+                stalled_nodes
+            )))
+        } else if self.seconds.is_some_and(|s| stalled_time >= s) {
+            Some(TerminationReason::new(format!(
+                "incumbent not improved for {:.1}s",
+                stalled_time
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+/// Stop once the incumbent reaches an absolute objective value, i.e. is at most (minimization)
+/// or at least (maximization) `target`. The sense is read from the model.
+pub struct ObjectiveTarget {
+    target: f64,
+    sense: GRBModelSense,
+}
+
+impl ObjectiveTarget {
+    pub fn new(target: f64) -> Self {
+        Self {
+            target,
+            sense: GRBModelSense::MINIMIZE,
+        }
+    }
+}
+
+impl TerminationPolicy for ObjectiveTarget {
+    fn prepare(&mut self, model: &GRBModel) {
+        if model.get(GRBIntAttr::MODELSENSE).ok() == Some(GRBModelSense::MAXIMIZE.into()) {
+            self.sense = GRBModelSense::MAXIMIZE;
+        }
+    }
+
+    fn check(&mut self, progress: &Progress) -> Option<TerminationReason> {
+        let incumbent = progress.incumbent?;
+        let reached = match self.sense {
+            GRBModelSense::MINIMIZE => incumbent <= self.target,
+            GRBModelSense::MAXIMIZE => incumbent >= self.target,
+        };
+        reached.then(|| {
+            TerminationReason::new(format!(
+                "objective {} reached target {}",
+                incumbent, self.target
+            ))
+        })
+    }
+}
+
+/// Stop once a wall-clock budget, measured from the start of the optimization, is used up.
+pub struct WallClock {
+    budget: Duration,
+    start: Option<Instant>,
+}
+
+impl WallClock {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            start: None,
+        }
+    }
+}
+
+impl TerminationPolicy for WallClock {
+    fn prepare(&mut self, _model: &GRBModel) {
+        self.start = Some(Instant::now());
+    }
+
+    fn check(&mut self, _progress: &Progress) -> Option<TerminationReason> {
+        // without `prepare`, e.g. when checked directly, the clock starts at the first check
+        let elapsed = self.start.get_or_insert_with(Instant::now).elapsed();
+        (elapsed >= self.budget).then(|| {
+            TerminationReason::new(format!(
+                "wall-clock budget of {:.1}s used up",
+                self.budget.as_secs_f64()
+            ))
+        })
+    }
+}
+
+struct PolicyCallback<P: TerminationPolicy> {
+    policy: RefCell<P>,
+    reason: Rc<RefCell<Option<TerminationReason>>>,
+}
+
+impl<P: TerminationPolicy> CallbackTrait for PolicyCallback<P> {
+    fn callback(&self, mut cb_ctx: GRBCallbackContext) {
+        if !matches!(GRBCallbackCodes::from(cb_ctx.where_), GRBCallbackCodes::MIP)
+            || self.reason.borrow().is_some()
+        {
+            return;
+        }
+        let Some(progress) = Progress::from_context(&mut cb_ctx) else {
+            return;
+        };
+        if let Some(reason) = self.policy.borrow_mut().check(&progress) {
+            *self.reason.borrow_mut() = Some(reason);
+            cb_ctx.abort();
+        }
+    }
+}
+
+impl GRBModel {
+    /// Optimize the model, evaluating `policy` on every `MIP` callback and stopping the
+    /// optimization as soon as it fires. The reason is available through `termination_reason`.
+    ///
+    /// This installs its own callback for the duration of the solve, a callback registered with
+    /// `set_callback` is not called and is restored afterwards.
+    pub fn optimize_with_policy<P: TerminationPolicy>(
+        &mut self,
+        mut policy: P,
+    ) -> Result<SolveOutcome, GRBError> {
+        // pending changes, e.g. of the model sense, have to be visible to `prepare`
+        self.update()?;
+        policy.prepare(self);
+        let reason = Rc::new(RefCell::new(None));
+        let mut callback = GRBCallback::new(PolicyCallback {
+            policy: RefCell::new(policy),
+            reason: reason.clone(),
+        });
+        let previous = self.callback;
+        self.set_callback(&mut callback);
+        let result = self.run_optimize();
+        // the callback is dropped at the end of this function, gurobi may not keep a pointer to it
+        self.register_callback(previous);
+        self.termination_reason = reason.take();
+        result?;
+        self.solve_outcome()
+    }
+
+    /// The reason a `TerminationPolicy` stopped the last optimization, if any.
+    pub fn termination_reason(&self) -> Option<&TerminationReason> {
+        self.termination_reason.as_ref()
+    }
+}
+
+/// The model pointer shared between a `GRBModel` and its `TerminateHandle`s. It is set to null
+/// when the `GRBModel` is dropped, after which terminating is a no-op.
+pub(crate) struct ModelSlot(pub(crate) *mut ffi::GRBmodel);
+
+// SAFETY: the pointer is only used to call `GRBterminate`, which Gurobi allows from any thread, and
+// it is only read while holding the mutex, so it cannot be freed concurrently.
+unsafe impl Send for ModelSlot {}
+
+/// Handle that stops a running `optimize` of a model from any thread, e.g. from a Ctrl-C handler.
+///
+/// Obtained with `GRBModel::terminate_handle`. Once the model is dropped, `terminate` does nothing.
+#[derive(Clone)]
+pub struct TerminateHandle {
+    slot: Arc<Mutex<ModelSlot>>,
+}
+
+impl TerminateHandle {
+    /// Request the optimization to stop as soon as possible, using
+    /// [GRBterminate](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/model.html#c.GRBterminate).
+    pub fn terminate(&self) {
+        let slot = self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        if !slot.0.is_null() {
+            unsafe {
+                ffi::GRBterminate(slot.0);
+            }
+        }
+    }
+}
+
+impl GRBModel {
+    /// Get a `Send + Sync` handle that can stop a running optimization of this model from another
+    /// thread.
+    pub fn terminate_handle(&self) -> TerminateHandle {
+        TerminateHandle {
+            slot: self.terminate_slot.clone(),
+        }
+    }
+}
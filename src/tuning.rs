@@ -0,0 +1,138 @@
+// Automatic parameter tuning with [GRBtunemodel](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/tuning.html#c.GRBtunemodel)
+// and [GRBtunemodels](https://docs.gurobi.com/projects/optimizer/en/current/reference/c/tuning.html#c.GRBtunemodels).
+//
+// The tuner is configured with the `TUNE*` parameters of the model (e.g. `GRBIntParam::TUNERESULTS`
+// or `GRBDblParam::TUNETIMELIMIT`). The results are read back with `GRBgettuneresult`, which loads
+// the parameters of a result into the model.
+
+use std::ptr::null_mut;
+use std::rc::Rc;
+
+use crate::{
+    env::{GRBParams, GRBenv, ParamScope},
+    error::{check_model_err, GRBError},
+    ffi,
+    model::GRBModel,
+    param_set::ParamSet,
+};
+
+/// The results of a tuning run, best first. Every item is the set of parameters the result
+/// changes compared to the settings of the model before tuning.
+///
+/// Reading a result loads its parameters into the tuned model (the first model for
+/// `GRBenv::tune_models`), so after iterating the model uses the parameters of the last result
+/// that was read. Use `ParamSet::apply_to_model` to switch to another one.
+pub struct TuneResults<'a> {
+    model: &'a GRBModel,
+    // non-default parameters of the model before tuning
+    baseline: ParamSet,
+    next: i32,
+    count: i32,
+}
+
+impl<'a> TuneResults<'a> {
+    fn new(model: &'a GRBModel, baseline: ParamSet) -> Result<Self, GRBError> {
+        let mut count = 0;
+        let error = unsafe {
+            ffi::GRBgetintattr(
+                *model.inner.0,
+                ffi::GRB_INT_ATTR_TUNE_RESULTCOUNT.as_ptr(),
+                &mut count,
+            )
+        };
+        check_model_err(*model.inner.0, error)?;
+        Ok(Self {
+            model,
+            baseline,
+            next: 0,
+            count,
+        })
+    }
+
+    /// Load the `index`-th result into the model and return the parameters it changes. Result 0
+    /// is the best parameter set found.
+    pub fn get(&mut self, index: usize) -> Result<ParamSet, GRBError> {
+        if index >= self.count as usize {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INDEX_OUT_OF_RANGE,
+                format!(
+                    "tune result {} out of range, there are {} results",
+                    index, self.count
+                ),
+            ));
+        }
+        let error = unsafe { ffi::GRBgettuneresult(*self.model.inner.0, index as i32) };
+        check_model_err(*self.model.inner.0, error)?;
+        let params = GRBParams::new(self.model.get_env(), ParamScope::Model);
+        Ok(ParamSet::changed_since(&params, &self.baseline))
+    }
+}
+
+impl Iterator for TuneResults<'_> {
+    type Item = Result<ParamSet, GRBError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count {
+            return None;
+        }
+        let result = self.get(self.next as usize);
+        self.next += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.next) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for TuneResults<'_> {}
+
+impl GRBModel {
+    fn param_snapshot(&self) -> ParamSet {
+        ParamSet::from_params(&GRBParams::new(self.get_env(), ParamScope::Model))
+    }
+
+    /// Run the parameter tuner on this model and return the improved parameter sets it found.
+    pub fn tune(&mut self) -> Result<TuneResults<'_>, GRBError> {
+        let baseline = self.param_snapshot();
+        let error = unsafe { ffi::GRBtunemodel(*self.inner.0) };
+        check_model_err(*self.inner.0, error)?;
+        TuneResults::new(self, baseline)
+    }
+}
+
+impl GRBenv {
+    /// Tune parameters over a set of models created in this environment, so the result works well
+    /// for all of them. The tuner uses the `TUNE*` parameters of the first model and the results
+    /// are read from it.
+    pub fn tune_models<'a>(&self, models: &[&'a GRBModel]) -> Result<TuneResults<'a>, GRBError> {
+        let Some(&first) = models.first() else {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                "no models to tune",
+            ));
+        };
+        if !models
+            .iter()
+            .all(|m| Rc::ptr_eq(&m.inner.1, &self.handle()))
+        {
+            return Err(GRBError::new(
+                ffi::GRB_ERROR_INVALID_ARGUMENT,
+                "the models were not created in this environment",
+            ));
+        }
+        let mut pointers: Vec<*mut ffi::GRBmodel> = models.iter().map(|m| *m.inner.0).collect();
+        let baseline = first.param_snapshot();
+        let error = unsafe {
+            ffi::GRBtunemodels(
+                pointers.len() as i32,
+                pointers.as_mut_ptr(),
+                null_mut(),
+                null_mut(),
+            )
+        };
+        check_model_err(*first.inner.0, error)?;
+        TuneResults::new(first, baseline)
+    }
+}
@@ -1,6 +1,12 @@
 use std::ffi::{CStr, CString};
 
-use crate::{error::check_err, ffi, model::GRBModelPtr, modeling::IsModelingObject};
+use crate::{
+    attributes::GRBAttrObject,
+    error::{check_err, GRBError},
+    ffi,
+    model::GRBModelPtr,
+    modeling::IsModelingObject,
+};
 
 pub trait VariableSetter {
     type Value;
@@ -9,7 +15,7 @@ pub trait VariableSetter {
 
 pub trait VariableGetter {
     type Value;
-    fn get(&self, var: &GRBVar) -> Self::Value;
+    fn get(&self, var: &GRBVar) -> Result<Self::Value, GRBError>;
 }
 
 #[allow(clippy::upper_case_acronyms, non_camel_case_types)]
@@ -50,7 +56,7 @@ impl GRBVar {
         self.get_error(err_code).unwrap();
     }
 
-    pub fn get<G: VariableGetter>(&self, getter: G) -> G::Value {
+    pub fn get<G: VariableGetter>(&self, getter: G) -> Result<G::Value, GRBError> {
         getter.get(self)
     }
 
@@ -73,6 +79,14 @@ impl IsModelingObject for GRBVar {
     fn index(&self) -> usize {
         self.index
     }
+
+    fn object(&self) -> GRBAttrObject {
+        GRBAttrObject::Var
+    }
+
+    fn model_ptr(&self) -> &GRBModelPtr {
+        &self.inner
+    }
 }
 
 // TODO: Get int attr
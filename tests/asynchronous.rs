@@ -0,0 +1,104 @@
+use std::thread;
+use std::time::Duration;
+
+use gurobirs::prelude::*;
+
+mod common;
+use common::market_split;
+
+// the model of the mip1 example, solved instantly
+fn mip1(env: &GRBenv) -> GRBModel {
+    let mut model = GRBModel::new(env);
+    model.set(GRBIntParam::OUTPUTFLAG, 0);
+    let x = model.add_var(
+        GRBVar::builder()
+            .vtype(GRBVarType::BINARY)
+            .name("x".to_string()),
+    );
+    let y = model.add_var(
+        GRBVar::builder()
+            .vtype(GRBVarType::BINARY)
+            .name("y".to_string()),
+    );
+    let z = model.add_var(
+        GRBVar::builder()
+            .vtype(GRBVarType::BINARY)
+            .name("z".to_string()),
+    );
+    model.set_objective(&x + &y + 2.0 * &z, GRBModelSense::MAXIMIZE);
+    model.add_constr((&x + 2.0 * &y + 3.0 * &z).le(4.0).name("c0"));
+    model.add_constr((&x + &y).ge(1.0).name("c1"));
+    model
+}
+
+#[test]
+fn test_join() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = mip1(&env);
+    let running = model.optimize_async().unwrap();
+    let outcome = running.join().unwrap();
+    assert_eq!(outcome.status, GRBStatus::OPTIMAL);
+    assert_eq!(outcome.obj_val, Some(3.0));
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
+}
+
+#[test]
+fn test_is_done_and_terminate() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = market_split(&env);
+    let running = model.optimize_async().unwrap();
+    assert!(!running.is_done().unwrap());
+    running.terminate();
+    while !running.is_done().unwrap() {
+        thread::sleep(Duration::from_millis(10));
+    }
+    let outcome = running.join().unwrap();
+    assert_eq!(outcome.status, GRBStatus::INTERRUPTED);
+}
+
+#[test]
+fn test_drop_waits_for_optimization() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = mip1(&env);
+    let running = model.optimize_async().unwrap();
+    drop(running);
+    // the model is synced again and can be queried as usual
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
+    assert_eq!(model.get(GRBDblAttr::OBJVAL).unwrap(), 3.0);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_wait() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = mip1(&env);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let outcome = runtime
+        .block_on(async {
+            let running = model.optimize_async()?;
+            running.wait(Duration::from_millis(10)).await
+        })
+        .unwrap();
+    assert_eq!(outcome.status, GRBStatus::OPTIMAL);
+    assert_eq!(outcome.obj_val, Some(3.0));
+}
+
+#[test]
+fn test_join_error() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    model.set(GRBIntParam::OUTPUTFLAG, 0);
+    model.set(GRBIntParam::NONCONVEX, 0);
+    let x = model.add_var(GRBVar::builder().ub(1.0).name("x".to_string()));
+    model.set_objective(-1.0 * (&x * &x), GRBModelSense::MINIMIZE);
+    // the error is reported either when starting or when finishing the optimization
+    let error = model
+        .optimize_async()
+        .and_then(|running| running.join())
+        .err()
+        .unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_Q_NOT_PSD);
+}
@@ -0,0 +1,166 @@
+use gurobirs::prelude::*;
+
+fn small_model(env: &GRBenv) -> (GRBModel, GRBVar, GRBVar, GRBConstr) {
+    let mut model = GRBModel::new(env);
+    let x = model.add_var(
+        GRBVar::builder()
+            .ub(4.0)
+            .obj(1.0)
+            .vtype(GRBVarType::INTEGER)
+            .name("x".to_string()),
+    );
+    let y = model.add_var(GRBVar::builder().ub(3.0).obj(2.0).name("y".to_string()));
+    let c = model.add_constr((&x + &y).le(5.0).name("c"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    (model, x, y, c)
+}
+
+#[test]
+fn test_model_attributes() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _, _, _) = small_model(&env);
+    model.set(GRBStrAttr::MODELNAME, "small".to_string());
+    model.set(GRBDblAttr::OBJCON, 1.5);
+    model.optimize();
+
+    assert_eq!(model.get(GRBIntAttr::NUMVARS).unwrap(), 2);
+    assert_eq!(model.get(GRBIntAttr::NUMCONSTRS).unwrap(), 1);
+    assert_eq!(model.get(GRBIntAttr::NUMINTVARS).unwrap(), 1);
+    assert_eq!(model.get(GRBIntAttr::MODELSENSE).unwrap(), -1);
+    assert_eq!(model.get(GRBStrAttr::MODELNAME).unwrap(), "small");
+    assert_eq!(model.get(GRBDblAttr::OBJCON).unwrap(), 1.5);
+    // x = 2, y = 3
+    assert_eq!(model.get(GRBDblAttr::OBJVAL).unwrap(), 2.0 + 6.0 + 1.5);
+    // solution quality
+    assert!(model.get(GRBDblAttr::BOUND_SVIO).unwrap() <= 1e-6);
+    assert!(model.get(GRBDblAttr::CONSTR_VIO_SUM).unwrap() <= 1e-6);
+    assert!(model.get(GRBDblAttr::INT_VIO).unwrap() <= 1e-6);
+}
+
+#[test]
+fn test_var_and_constr_attributes() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_model(&env);
+    x.set(GRBDblAttr::LB, 1.0);
+    y.set(GRBCharAttr::VTYPE, 'I');
+    x.set(GRBStrAttr::VARNAME, "renamed".to_string());
+    c.set(GRBDblAttr::RHS, 4.0);
+    model.optimize();
+
+    assert_eq!(x.get(GRBDblAttr::LB).unwrap(), 1.0);
+    assert_eq!(x.get(GRBDblAttr::UB).unwrap(), 4.0);
+    assert_eq!(y.get(GRBCharAttr::VTYPE).unwrap(), 'I');
+    assert_eq!(x.get(GRBStrAttr::VARNAME).unwrap(), "renamed");
+    assert_eq!(c.get(GRBCharAttr::SENSE).unwrap(), '<');
+    assert_eq!(c.get(GRBStrAttr::CONSTRNAME).unwrap(), "c");
+    assert_eq!(c.get(GRBDblAttr::RHS).unwrap(), 4.0);
+    assert_eq!(c.get(GRBDblAttr::SLACK).unwrap(), 0.0);
+    assert_eq!(y.get(GRBDblAttr::X).unwrap(), 3.0);
+
+    let vtypes = model
+        .get_list(GRBCharAttr::VTYPE, &[x.clone(), y.clone()])
+        .unwrap();
+    assert_eq!(vtypes, vec!['I', 'I']);
+    let names = model
+        .get_list(GRBStrAttr::VARNAME, &[x.clone(), y.clone()])
+        .unwrap();
+    assert_eq!(names, vec!["renamed".to_string(), "y".to_string()]);
+}
+
+#[test]
+fn test_attribute_errors() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (model, x, _, _) = small_model(&env);
+    // no solution yet
+    let error = model.get(GRBDblAttr::OBJVAL).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_DATA_NOT_AVAILABLE);
+    assert!(x.get(GRBDblAttr::X).is_err());
+}
+
+#[test]
+fn test_attribute_objects() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, _, c) = small_model(&env);
+    model.optimize();
+
+    assert_eq!(GRBDblAttr::X.object(), GRBAttrObject::Var);
+    assert_eq!(GRBDblAttr::SA_RHSLOW.object(), GRBAttrObject::Constr);
+    assert_eq!(GRBIntAttr::TUNE_RESULTCOUNT.object(), GRBAttrObject::Model);
+    assert!(GRBDblAttr::<kind::Var>::ALL.contains(&GRBDblAttr::UNBDRAY));
+    assert_eq!(GRBIntAttr::PWLOBJCVX.object(), GRBAttrObject::Var);
+    assert_eq!(GRBDblAttr::COMPL_VIO.object(), GRBAttrObject::Model);
+    assert_eq!(GRBIntAttr::CONSTR_SVIO_INDEX.object(), GRBAttrObject::Model);
+    assert_eq!(GRBIntAttr::BATCHSTATUS.object(), GRBAttrObject::Batch);
+    assert_eq!(GRBStrAttr::BATCHID.object(), GRBAttrObject::Batch);
+    let name: &std::ffi::CStr = GRBStrAttr::VARNAME.into();
+    assert_eq!(name, c"VarName");
+
+    // a `GRBConstr` rejects the attributes of other kinds of constraint instead of reading another
+    // constraint, attributes of variables or the model do not compile on it
+    let error = c.get(GRBDblAttr::QCRHS).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_UNKNOWN_ATTRIBUTE);
+    assert_eq!(c.get(GRBStrAttr::CONSTRNAME).unwrap(), "c");
+    assert_eq!(x.get(GRBStrAttr::VARNAME).unwrap(), "x");
+}
+
+#[test]
+fn test_bulk_attributes() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, _) = small_model(&env);
+    model
+        .set_list(GRBDblAttr::OBJ, &[x.clone(), y.clone()], &[3.0, 1.0])
+        .unwrap();
+    model.set_array(GRBDblAttr::UB, 0, &[4.0, 4.0]);
+    model.optimize();
+
+    // x = 4, y = 1
+    assert_eq!(
+        model
+            .get_list(GRBDblAttr::X, std::slice::from_ref(&y))
+            .unwrap(),
+        vec![1.0]
+    );
+    assert_eq!(
+        model.get_array(GRBDblAttr::X, 0, 2).unwrap(),
+        vec![4.0, 1.0]
+    );
+    assert_eq!(model.get_all(GRBDblAttr::X).unwrap(), vec![4.0, 1.0]);
+    assert_eq!(model.get_all(GRBStrAttr::VARNAME).unwrap(), vec!["x", "y"]);
+    assert_eq!(model.get_all(GRBDblAttr::SLACK).unwrap(), vec![0.0]);
+
+    let mut values = [0.0; 2];
+    model
+        .get_array_into(GRBDblAttr::UB, 0, &mut values)
+        .unwrap();
+    assert_eq!(values, [4.0, 4.0]);
+
+    assert!(model.get_array(GRBDblAttr::X, 1, 5).is_err());
+}
+
+#[test]
+fn test_bulk_attribute_handles() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_model(&env);
+    let q = model.add_qconstr((&x * &x).le(9.0).name("q"));
+    model.update().unwrap();
+
+    // handles of another kind than the attribute are rejected, even with a valid index
+    let error = model.get_list(GRBDblAttr::RHS, &[&c, &q]).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    let error = model.set_list(GRBDblAttr::RHS, &[&q], &[1.0]).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    assert_eq!(c.get(GRBDblAttr::RHS).unwrap(), 5.0);
+
+    // as are handles of another model
+    let (_other, other_x, _, _) = small_model(&env);
+    let error = model
+        .get_list(GRBStrAttr::VARNAME, &[&x, &other_x])
+        .unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    let error = model
+        .set_list(GRBDblAttr::UB, &[&other_x, &y], &[1.0, 1.0])
+        .unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    model.update().unwrap();
+    assert_eq!(y.get(GRBDblAttr::UB).unwrap(), 3.0);
+}
@@ -0,0 +1,121 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::small_lp;
+
+const N: usize = 40;
+
+// max sum (i + 1) x_i  s.t.  x_i + x_{i+1} <= rhs_i,  0 <= x_i <= 10
+fn chain_lp(env: &GRBenv, first_rhs: f64) -> (GRBModel, Vec<GRBVar>) {
+    let mut model = GRBModel::new(env);
+    let vars: Vec<GRBVar> = (0..N)
+        .map(|i| {
+            model.add_var(
+                GRBVar::builder()
+                    .obj((i + 1) as f64)
+                    .ub(10.0)
+                    .name(format!("x{}", i)),
+            )
+        })
+        .collect();
+    for i in 0..N - 1 {
+        let rhs = if i == 0 {
+            first_rhs
+        } else {
+            8.0 + (i % 5) as f64
+        };
+        model.add_constr((&vars[i] + &vars[i + 1]).le(rhs).name(&format!("c{}", i)));
+    }
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    model.set_typed(Presolve::Off).unwrap();
+    model.set_typed(Method::DualSimplex).unwrap();
+    (model, vars)
+}
+
+fn iterations(model: &mut GRBModel) -> f64 {
+    let outcome = model.optimize();
+    assert_eq!(outcome.status, GRBStatus::OPTIMAL);
+    model.get(GRBDblAttr::ITERCOUNT).unwrap()
+}
+
+#[test]
+fn test_basis_warm_start() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, vars) = chain_lp(&env, 8.0);
+    model.optimize();
+    let basis = model.basis().unwrap();
+    assert_eq!(basis.vars.len(), N);
+    assert_eq!(basis.constrs.len(), N - 1);
+    assert!(basis.vars.contains(&BasisStatus::Basic));
+    // every variable that is strictly between its bounds is basic
+    for var in &vars {
+        let x = var.get(GRBDblAttr::X).unwrap();
+        if x > 1e-6 && x < 10.0 - 1e-6 {
+            assert_eq!(basis.var(var), Some(BasisStatus::Basic));
+        }
+    }
+
+    // the same LP after a small rhs change, solved from scratch and from the old basis
+    let (mut cold, _) = chain_lp(&env, 9.0);
+    let cold_iterations = iterations(&mut cold);
+
+    let (mut warm, _) = chain_lp(&env, 9.0);
+    warm.set_basis(&basis).unwrap();
+    let warm_iterations = iterations(&mut warm);
+    assert!(warm_iterations < cold_iterations);
+    assert_eq!(
+        warm.get(GRBDblAttr::OBJVAL).unwrap(),
+        cold.get(GRBDblAttr::OBJVAL).unwrap()
+    );
+
+    // the same warm start through a .bas file
+    let path = std::env::temp_dir().join("gurobirs_basis.bas");
+    let path = path.to_str().unwrap();
+    model.write(path).unwrap();
+    let (mut from_file, _) = chain_lp(&env, 9.0);
+    from_file.read(path).unwrap();
+    assert!(iterations(&mut from_file) < cold_iterations);
+}
+
+#[test]
+fn test_set_basis_size_mismatch() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _) = chain_lp(&env, 8.0);
+    let basis = Basis {
+        vars: vec![BasisStatus::NonbasicLower; N - 1],
+        constrs: vec![BasisStatus::Basic; N - 1],
+    };
+    assert!(model.set_basis(&basis).is_err());
+}
+
+#[test]
+fn test_basis_constraints() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, _, c) = small_lp(&env, false);
+    model.optimize();
+    let basis = model.basis().unwrap();
+    // x is strictly between its bounds, so the tight row is nonbasic
+    assert_eq!(basis.var(&x), Some(BasisStatus::Basic));
+    assert!(matches!(
+        basis.constr(&c),
+        Some(BasisStatus::NonbasicLower | BasisStatus::NonbasicUpper)
+    ));
+
+    // quadratic constraints are not part of the basis
+    let z = model.add_var(GRBVar::builder().name("z".to_string()));
+    let q = model.add_qconstr((&z * &z).le(1.0).name("q"));
+    assert_eq!(q.index, c.index);
+    assert_eq!(basis.constr(&q), None);
+    // neither are variables added after the basis was read
+    assert_eq!(basis.var(&z), None);
+}
+
+#[test]
+fn test_basis_status_conversion() {
+    assert_eq!(
+        BasisStatus::try_from(gurobirs_sys::GRB_SUPERBASIC).unwrap(),
+        BasisStatus::SuperBasic
+    );
+    let error = BasisStatus::try_from(7).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_VALUE_OUT_OF_RANGE);
+}
@@ -0,0 +1,16 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::market_split;
+
+#[test]
+fn test_batch_without_cluster_manager() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = market_split(&env);
+    // batches are submitted to a Cluster Manager, which this environment does not connect to
+    assert!(model.optimize_batch().is_err());
+    assert!(GRBbatch::new(&env, "no-such-batch").is_err());
+
+    let error = GRBbatch::new(&env, "batch\0id").err().unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+}
@@ -15,7 +15,7 @@ fn test_build_model() {
     // first create env
     let env =
         GRBenv::new(false, None).expect("err nerrr sth happened when creating the environment");
-    let mut model = GRBModel::new(env);
+    let mut model = GRBModel::new(&env);
 
     let x = model.add_var(
         GRBVar::builder()
@@ -36,6 +36,6 @@ fn test_build_model() {
     let mut callback = GRBCallback::new(MyCallback);
     model.set_callback(&mut callback);
     model.optimize();
-    println!("x: {}", x.get(GRBDblAttr::X));
-    println!("y: {}", y.get(GRBDblAttr::X));
+    println!("x: {}", x.get(GRBDblAttr::X).unwrap());
+    println!("y: {}", y.get(GRBDblAttr::X).unwrap());
 }
@@ -0,0 +1,117 @@
+// Models shared by several integration tests. Every test binary compiles this module, but not
+// every binary uses every model.
+#![allow(dead_code)]
+
+use gurobirs::prelude::*;
+
+// market split instance: hard to solve for its size, so it is still running when terminated
+pub fn market_split(env: &GRBenv) -> GRBModel {
+    let mut model = GRBModel::new(env);
+    model.set(GRBIntParam::OUTPUTFLAG, 0);
+    model.set(GRBDblParam::TIMELIMIT, 60.0);
+    let vars: Vec<GRBVar> = (0..40)
+        .map(|j| {
+            model.add_var(
+                GRBVar::builder()
+                    .vtype(GRBVarType::BINARY)
+                    .name(format!("x{}", j)),
+            )
+        })
+        .collect();
+    let mut seed: u64 = 12345;
+    for i in 0..5 {
+        let mut expr = GRBLinExpr::new();
+        let mut total = 0.0;
+        for var in &vars {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let a = ((seed >> 33) % 100) as f64;
+            expr += var * a;
+            total += a;
+        }
+        model.add_constr(expr.eq((total / 2.0).floor()).name(&format!("split{}", i)));
+    }
+    model
+}
+
+// binary knapsack with a single capacity row `c`: max x + y + 2z s.t. x + y + z <= 2
+pub fn knapsack(env: &GRBenv) -> (GRBModel, Vec<GRBVar>) {
+    let mut model = GRBModel::new(env);
+    let vars: Vec<GRBVar> = [("x", 1.0), ("y", 1.0), ("z", 2.0)]
+        .into_iter()
+        .map(|(name, obj)| {
+            model.add_var(
+                GRBVar::builder()
+                    .obj(obj)
+                    .vtype(GRBVarType::BINARY)
+                    .name(name.to_string()),
+            )
+        })
+        .collect();
+    model.add_constr((&vars[0] + &vars[1] + &vars[2]).le(2.0).name("c"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    (model, vars)
+}
+
+// max x + 2 y  s.t.  x + y <= 4,  y <= 3, optionally with x and y integer
+pub fn small_lp(env: &GRBenv, integer: bool) -> (GRBModel, GRBVar, GRBVar, GRBConstr) {
+    let vtype = || {
+        if integer {
+            GRBVarType::INTEGER
+        } else {
+            GRBVarType::CONTINUOUS
+        }
+    };
+    let mut model = GRBModel::new(env);
+    let x = model.add_var(
+        GRBVar::builder()
+            .obj(1.0)
+            .vtype(vtype())
+            .name("x".to_string()),
+    );
+    let y = model.add_var(
+        GRBVar::builder()
+            .obj(2.0)
+            .ub(3.0)
+            .vtype(vtype())
+            .name("y".to_string()),
+    );
+    let c = model.add_constr((&x + &y).le(4.0).name("c"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    (model, x, y, c)
+}
+
+// max x + 2 y  s.t.  x + y <= 3.5,  x, y integer in [0, 2]
+pub fn small_mip(env: &GRBenv) -> (GRBModel, GRBVar, GRBVar, GRBConstr) {
+    let mut model = GRBModel::new(env);
+    let x = model.add_var(
+        GRBVar::builder()
+            .obj(1.0)
+            .ub(2.0)
+            .vtype(GRBVarType::INTEGER)
+            .name("x".to_string()),
+    );
+    let y = model.add_var(
+        GRBVar::builder()
+            .obj(2.0)
+            .ub(2.0)
+            .vtype(GRBVarType::INTEGER)
+            .name("y".to_string()),
+    );
+    let c = model.add_constr((&x + &y).le(3.5).name("c"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    (model, x, y, c)
+}
+
+// x + y >= 3 (`sum`) is infeasible with x, y <= 1, the redundant constraint z <= 5 is not in the
+// IIS
+pub fn infeasible(env: &GRBenv) -> (GRBModel, GRBVar, GRBVar, GRBConstr) {
+    let mut model = GRBModel::new(env);
+    let x = model.add_var(GRBVar::builder().obj(1.0).ub(1.0).name("x".to_string()));
+    let y = model.add_var(GRBVar::builder().obj(1.0).ub(1.0).name("y".to_string()));
+    let z = model.add_var(GRBVar::builder().name("z".to_string()));
+    let c = model.add_constr((&x + &y).ge(3.0).name("sum"));
+    model.add_constr((&z + 0.0).le(5.0).name("redundant"));
+    (model, x, y, c)
+}
@@ -0,0 +1,89 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::small_mip;
+
+#[test]
+fn test_relax_and_fixed() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_mip(&env);
+
+    let mut relaxed = model.relax().unwrap();
+    assert_eq!(relaxed.model.get(GRBIntAttr::IS_MIP).unwrap(), 0);
+    relaxed.model.optimize();
+    assert_eq!(relaxed.model.get(GRBDblAttr::OBJVAL).unwrap(), 5.5);
+    let relaxed_x = relaxed.var(&x).unwrap();
+    assert_eq!(relaxed_x.get(GRBDblAttr::X).unwrap(), 1.5);
+
+    model.optimize();
+    assert_eq!(model.get(GRBDblAttr::OBJVAL).unwrap(), 5.0);
+    let mut fixed = model.fixed().unwrap();
+    fixed.model.optimize();
+    assert_eq!(fixed.var(&y).unwrap().get(GRBDblAttr::X).unwrap(), 2.0);
+    // dual prices are available on the fixed model
+    assert!(fixed.constr(&c).unwrap().get(GRBDblAttr::PI).is_ok());
+    assert!(c.get(GRBDblAttr::PI).is_err());
+}
+
+#[test]
+fn test_copy_and_presolve() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, _) = small_mip(&env);
+
+    // changes to the copy do not affect the original
+    let copy = model.copy().unwrap();
+    copy.var(&y).unwrap().set(GRBDblAttr::UB, 0.0);
+    let mut copy = copy.into_model();
+    copy.optimize();
+    model.optimize();
+    assert_eq!(copy.get(GRBDblAttr::OBJVAL).unwrap(), 2.0);
+    assert_eq!(model.get(GRBDblAttr::OBJVAL).unwrap(), 5.0);
+
+    // fixing x to its upper bound lets presolve remove it
+    x.set(GRBDblAttr::LB, 2.0);
+    let presolved = model.presolve().unwrap();
+    assert!(presolved.var(&x).is_none());
+    assert!(presolved.model.get(GRBIntAttr::NUMVARS).unwrap() <= 1);
+}
+
+#[test]
+fn test_constraint_kinds() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_mip(&env);
+    let z = model.add_var(GRBVar::builder().name("z".to_string()));
+    let q = model.add_qconstr((&x * &x + &y * &y).le(8.0).name("q"));
+    let g = model.add_genconstr_max(z, vec![x.clone(), y.clone()], 0.0, "g");
+
+    // linear, quadratic and general constraints are mapped separately, keeping their kind
+    let copy = model.copy().unwrap();
+    let copy_c = copy.constr(&c).unwrap();
+    assert_eq!(copy_c.kind(), GRBAttrObject::Constr);
+    assert_eq!(copy_c.get(GRBStrAttr::CONSTRNAME).unwrap(), "c");
+    let copy_q = copy.constr(&q).unwrap();
+    assert_eq!((copy_q.index, copy_q.kind()), (0, GRBAttrObject::QConstr));
+    assert_eq!(copy_q.get(GRBStrAttr::QCNAME).unwrap(), "q");
+    let copy_g = copy.constr(&g).unwrap();
+    assert_eq!((copy_g.index, copy_g.kind()), (0, GRBAttrObject::GenConstr));
+    assert_eq!(copy_g.get(GRBStrAttr::GENCONSTRNAME).unwrap(), "g");
+
+    // handles of another model are not mapped
+    let (_other, other_x, _, other_c) = small_mip(&env);
+    assert!(copy.var(&other_x).is_none());
+    assert!(copy.constr(&other_c).is_none());
+}
+
+#[test]
+fn test_presolve_duplicate_names() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().obj(1.0).ub(2.0).name("v".to_string()));
+    let y = model.add_var(GRBVar::builder().obj(1.0).ub(2.0).name("v".to_string()));
+    let z = model.add_var(GRBVar::builder().obj(1.0).ub(2.0).name("z".to_string()));
+    model.add_constr((&x + &y + &z).le(3.0).name("c"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+
+    // there is no telling the variables named `v` apart in the presolved model
+    let presolved = model.presolve().unwrap();
+    assert!(presolved.var(&x).is_none());
+    assert!(presolved.var(&y).is_none());
+}
@@ -0,0 +1,60 @@
+use gurobirs::prelude::*;
+
+#[test]
+fn test_env_builder() {
+    let env = GRBenv::builder()
+        .output_flag(false)
+        .param(GRBIntParam::THREADS, 2)
+        .param(GRBDblParam::TIMELIMIT, 30.0)
+        .start()
+        .unwrap();
+    assert_eq!(env.get(GRBIntParam::OUTPUTFLAG).unwrap(), 0);
+    assert_eq!(env.get(GRBIntParam::THREADS).unwrap(), 2);
+
+    // models created from the started env copy the parameters
+    let model = GRBModel::new(&env);
+    assert_eq!(model.get_param(GRBDblParam::TIMELIMIT).unwrap(), 30.0);
+}
+
+#[test]
+fn test_env_builder_reports_start_errors() {
+    // no compute server is listening here, so starting the env must fail instead of panicking
+    let result = GRBenv::builder()
+        .output_flag(false)
+        .compute_server("localhost:1")
+        .start();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_env_from_prm_file() {
+    let mut set = ParamSet::new();
+    set.set(GRBIntParam::OUTPUTFLAG, 0)
+        .set(GRBDblParam::MIPGAP, 0.02);
+    let path = std::env::temp_dir().join("gurobirs_env.prm");
+    std::fs::write(&path, set.to_prm()).unwrap();
+
+    let env = GRBenv::from_prm_file(path.to_str().unwrap()).unwrap();
+    assert_eq!(env.get(GRBDblParam::MIPGAP).unwrap(), 0.02);
+    let error = GRBenv::from_prm_file("does/not/exist.prm").err().unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_FILE_READ);
+    let error = GRBenv::new(false, Some("gurobi\0.log")).err().unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+}
+
+#[test]
+fn test_model_keeps_env_alive() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    // passing the env by value works as well
+    let other = GRBModel::new(GRBenv::new(false, None).unwrap());
+    drop(env);
+
+    let x = model.add_var(GRBVar::builder().obj(1.0).ub(2.0).name("x".to_string()));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    model.optimize();
+    drop(model);
+    // handles keep the model, and so the env, alive as well
+    assert_eq!(x.get(GRBDblAttr::X).unwrap(), 2.0);
+    assert_eq!(other.get(GRBIntAttr::NUMVARS).unwrap(), 0);
+}
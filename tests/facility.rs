@@ -1,5 +1,4 @@
 use gurobirs::prelude::*;
-use gurobirs_sys::GRB_METHOD_BARRIER;
 
 #[test]
 fn test_facility_location() {
@@ -22,7 +21,7 @@ fn test_facility_location() {
     ];
     // Create a new Gurobi environment
     let env = GRBenv::new(false, None).unwrap();
-    let mut model = GRBModel::new(env);
+    let mut model = GRBModel::new(&env);
     model.set(GRBStrAttr::MODELNAME, "facility_location".to_string());
 
     // plant open
@@ -47,7 +46,7 @@ fn test_facility_location() {
         }
     }
 
-    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MINIMIZE as i32);
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MINIMIZE.into());
     // Production capacity constraints
     // RHS is limited by choice of opening plant
     for p in 0..num_plants {
@@ -84,19 +83,19 @@ fn test_facility_location() {
     open[plant_to_close].set(GRBDblAttr::START, 0.0);
 
     // use barrier in root
-    model.set(GRBIntParam::METHOD, GRB_METHOD_BARRIER);
+    model.set_typed(Method::Barrier).unwrap();
 
     // solve
     model.optimize();
 
     // print solution
-    println!("TOTAL COSTS: {}", model.get(GRBDblAttr::OBJVAL));
+    println!("TOTAL COSTS: {}", model.get(GRBDblAttr::OBJVAL).unwrap());
     println!("SOLUTION:");
     for p in 0..num_plants {
-        if open[p].get(GRBDblAttr::X) > 0.5 {
+        if open[p].get(GRBDblAttr::X).unwrap() > 0.5 {
             println!("Plant {} is open", p);
             for w in 0..num_warehouses {
-                let shipped = transport[w][p].get(GRBDblAttr::X);
+                let shipped = transport[w][p].get(GRBDblAttr::X).unwrap();
                 if shipped > 0.0001 {
                     println!("  ships {} units to warehouse {}", shipped, w);
                 }
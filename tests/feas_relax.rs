@@ -0,0 +1,114 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::infeasible;
+
+#[test]
+fn test_feas_relax_constr() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _, _, c) = infeasible(&env);
+    model.optimize();
+    assert_eq!(model.status(), GRBStatus::INFEASIBLE);
+
+    let result = model
+        .feas_relax(RelaxType::Linear, true, &[], &[(&c, 1.0)])
+        .unwrap();
+    assert_eq!(result.feas_obj, Some(1.0));
+    assert!(!result.artificials.is_empty());
+    for (art, relaxed) in &result.artificials {
+        match relaxed {
+            Relaxed::ConstrPositive(relaxed) | Relaxed::ConstrNegative(relaxed) => {
+                assert_eq!(relaxed.index, c.index)
+            }
+            _ => panic!("only the constraint is relaxed"),
+        }
+        assert!(art.get(GRBStrAttr::VARNAME).unwrap().ends_with("_sum"));
+    }
+
+    model.optimize();
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
+    let violation: f64 = result
+        .artificials
+        .iter()
+        .map(|(art, _)| art.get(GRBDblAttr::X).unwrap())
+        .sum();
+    assert_eq!(violation, 1.0);
+}
+
+#[test]
+fn test_feas_relax_all() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _, _, _) = infeasible(&env);
+    let num_vars = model.get(GRBIntAttr::NUMVARS).unwrap() as usize;
+    let result = model.feas_relax_all(RelaxType::Cardinality, false).unwrap();
+    assert_eq!(result.feas_obj, None);
+    let relaxes_ub_of_x = |relaxed: &Relaxed| match relaxed {
+        Relaxed::UpperBound(var) => var.get(GRBStrAttr::VARNAME).unwrap() == "x",
+        _ => false,
+    };
+    assert!(result.artificials.iter().any(|(_, r)| relaxes_ub_of_x(r)));
+    // artificial variables are named after what they relax
+    assert!(result
+        .artificials
+        .iter()
+        .all(|(art, _)| art.get(GRBStrAttr::VARNAME).unwrap().starts_with("Art")));
+    assert_eq!(
+        model.get(GRBIntAttr::NUMVARS).unwrap() as usize,
+        num_vars + result.artificials.len()
+    );
+
+    model.optimize();
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
+    // violating a single bound or the constraint suffices
+    assert_eq!(model.get(GRBDblAttr::OBJVAL).unwrap(), 1.0);
+}
+
+#[test]
+fn test_feas_relax_duplicate_names() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().ub(1.0).name("x".to_string()));
+    let first = model.add_constr((1.0 * &x).ge(2.0).name("c"));
+    let second = model.add_constr((1.0 * &x).ge(3.0).name("c"));
+    let penalties = [1.0, 2.0];
+
+    let result = model
+        .feas_relax(
+            RelaxType::Linear,
+            false,
+            &[],
+            &[(&first, penalties[0]), (&second, penalties[1])],
+        )
+        .unwrap();
+    assert_eq!(result.artificials.len(), 2);
+    // both artificials are named `ArtP_c`, the penalty shows which constraint each one relaxes
+    let mut relaxed = Vec::new();
+    for (art, r) in &result.artificials {
+        let Relaxed::ConstrPositive(c) = r else {
+            panic!("only >= constraints are relaxed");
+        };
+        assert_eq!(art.get(GRBDblAttr::OBJ).unwrap(), penalties[c.index]);
+        relaxed.push(c.index);
+    }
+    relaxed.sort();
+    assert_eq!(relaxed, vec![first.index, second.index]);
+}
+
+#[test]
+fn test_feas_relax_invalid_handles() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, _, _) = infeasible(&env);
+    let (_other, _, _, other_c) = infeasible(&env);
+    let error = model
+        .feas_relax(RelaxType::Linear, false, &[], &[(&other_c, 1.0)])
+        .err()
+        .unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+
+    // only linear constraints can be relaxed
+    let q = model.add_qconstr((&x * &x).le(4.0).name("q"));
+    assert!(model
+        .feas_relax(RelaxType::Linear, false, &[], &[(&q, 1.0)])
+        .is_err());
+    assert_eq!(model.get(GRBIntAttr::NUMVARS).unwrap(), 3);
+}
@@ -0,0 +1,50 @@
+use gurobirs::prelude::*;
+
+#[test]
+fn test_hints() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let vars: Vec<GRBVar> = (0..3)
+        .map(|i| {
+            model.add_var(
+                GRBVar::builder()
+                    .obj(1.0)
+                    .vtype(GRBVarType::BINARY)
+                    .name(format!("x{}", i)),
+            )
+        })
+        .collect();
+    model.add_constr((&vars[0] + &vars[1] + &vars[2]).le(1.0).name("c"));
+
+    model
+        .set_hints(&[(&vars[0], 1.0, 10), (&vars[2], 0.0, 1)])
+        .unwrap();
+    let hints = model.hints().unwrap();
+    assert_eq!(hints, vec![Some((1.0, 10)), None, Some((0.0, 1))]);
+
+    // round trip through a .hnt file
+    let path = std::env::temp_dir().join("gurobirs_hints.hnt");
+    let path = path.to_str().unwrap();
+    model.write(path).unwrap();
+    model.clear_hints().unwrap();
+    assert_eq!(model.hints().unwrap(), vec![None; 3]);
+    model.read(path).unwrap();
+    assert_eq!(model.hints().unwrap(), hints);
+
+    model.optimize();
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
+}
+
+#[test]
+fn test_hints_foreign_variable() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().name("x".to_string()));
+    let mut other = GRBModel::new(&env);
+    let y = other.add_var(GRBVar::builder().name("y".to_string()));
+
+    let error = model.set_hints(&[(&x, 1.0, 1), (&y, 1.0, 1)]).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    // nothing was written
+    assert_eq!(model.hints().unwrap(), vec![None]);
+}
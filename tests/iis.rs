@@ -0,0 +1,132 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::infeasible;
+
+#[test]
+fn test_compute_iis() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _, _, c) = infeasible(&env);
+    model.optimize();
+    assert_eq!(model.status(), GRBStatus::INFEASIBLE);
+
+    let report = model
+        .compute_iis_with_progress(|progress| assert!(progress.constr_min <= progress.constr_max))
+        .unwrap();
+    assert!(report.minimal);
+    assert_eq!(report.len(), 3);
+    assert_eq!(report.constrs.len(), 1);
+    assert_eq!(report.constrs[0].0.index, c.index);
+    assert_eq!(report.constrs[0].1, "sum");
+    assert!(report.lower_bounds.is_empty());
+    let mut bounds: Vec<&str> = report
+        .upper_bounds
+        .iter()
+        .map(|(_, n)| n.as_str())
+        .collect();
+    bounds.sort();
+    assert_eq!(bounds, vec!["x", "y"]);
+    assert_eq!(report.upper_bounds[0].0.get(GRBDblAttr::UB).unwrap(), 1.0);
+
+    let path = std::env::temp_dir().join("gurobirs_iis.ilp");
+    model.write(path.to_str().unwrap()).unwrap();
+    let ilp = std::fs::read_to_string(path).unwrap();
+    assert!(ilp.contains("sum"));
+    assert!(!ilp.contains("redundant"));
+}
+
+#[test]
+fn test_iis_force() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    // both constraints conflict with the upper bound of x, each on its own is an IIS
+    let x = model.add_var(GRBVar::builder().ub(1.0).name("x".to_string()));
+    let c1 = model.add_constr((1.0 * &x).ge(2.0).name("c1"));
+    let c2 = model.add_constr((1.0 * &x).ge(3.0).name("c2"));
+    let names = |report: &IisReport| {
+        report
+            .constrs
+            .iter()
+            .map(|(_, name)| name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    model
+        .set_iis_constr_force(&[&c1], IisForce::Exclude)
+        .unwrap();
+    let report = model.compute_iis().unwrap();
+    assert_eq!(names(&report), vec!["c2"]);
+
+    model.set_iis_constr_force(&[&c1], IisForce::Auto).unwrap();
+    model
+        .set_iis_constr_force(&[&c2], IisForce::Exclude)
+        .unwrap();
+    let report = model.compute_iis().unwrap();
+    assert_eq!(names(&report), vec!["c1"]);
+
+    // forcing the bound out leaves no IIS
+    model.set_iis_constr_force(&[&c2], IisForce::Auto).unwrap();
+    model.set_iis_ub_force(&[&x], IisForce::Exclude).unwrap();
+    assert!(model.compute_iis().is_err());
+
+    // forcing a member in keeps it in the IIS
+    model.set_iis_ub_force(&[&x], IisForce::Auto).unwrap();
+    model
+        .set_iis_constr_force(&[&c1, &c2], IisForce::Include)
+        .unwrap();
+    let mut both = names(&model.compute_iis().unwrap());
+    both.sort();
+    assert_eq!(both, vec!["c1", "c2"]);
+}
+
+#[test]
+fn test_iis_constraint_kinds() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    // y = max(x, z) is at least 3, but y^2 <= 4
+    let x = model.add_var(GRBVar::builder().ub(10.0).name("x".to_string()));
+    let y = model.add_var(GRBVar::builder().ub(10.0).name("y".to_string()));
+    let z = model.add_var(GRBVar::builder().lb(3.0).ub(10.0).name("z".to_string()));
+    let q = model.add_qconstr((&y * &y).le(4.0).name("q"));
+    let g = model.add_genconstr_max(y.clone(), vec![x.clone(), z.clone()], 0.0, "g");
+
+    let report = model.compute_iis().unwrap();
+    assert_eq!(report.qconstrs.len(), 1);
+    let (qconstr, name) = &report.qconstrs[0];
+    assert_eq!(qconstr.kind(), GRBAttrObject::QConstr);
+    assert_eq!(qconstr.index, q.index);
+    assert_eq!(name, "q");
+    assert_eq!(report.gen_constrs.len(), 1);
+    let (gen_constr, name) = &report.gen_constrs[0];
+    assert_eq!(gen_constr.kind(), GRBAttrObject::GenConstr);
+    assert_eq!(gen_constr.index, g.index);
+    assert_eq!(name, "g");
+    // the handles can be used to read attributes of the constraints
+    assert_eq!(qconstr.get(GRBDblAttr::QCRHS).unwrap(), 4.0);
+    assert_eq!(gen_constr.get(GRBStrAttr::GENCONSTRNAME).unwrap(), "g");
+}
+
+#[test]
+fn test_iis_errors() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, _, _) = infeasible(&env);
+    model.set_iis_lb_force(&[&x], IisForce::Exclude).unwrap();
+    model.update().unwrap();
+    assert_eq!(x.get(GRBIntAttr::IIS_LBFORCE).unwrap(), 0);
+
+    // handles of another model are rejected
+    let (_other, other_x, _, other_c) = infeasible(&env);
+    let error = model
+        .set_iis_ub_force(&[&other_x], IisForce::Include)
+        .unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    assert!(model
+        .set_iis_constr_force(&[&other_c], IisForce::Include)
+        .is_err());
+
+    // a feasible model has no IIS
+    let mut feasible = GRBModel::new(&env);
+    feasible.add_var(GRBVar::builder().name("x".to_string()));
+    feasible.optimize();
+    assert!(feasible.compute_iis().is_err());
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+
+use gurobirs::prelude::*;
+
+// records every `(target, message)` it receives
+struct TestLogger {
+    records: Mutex<Vec<(String, String)>>,
+}
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.records
+            .lock()
+            .unwrap()
+            .push((record.target().to_string(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: TestLogger = TestLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn test_log_forwarding() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    model.set(GRBIntParam::LOGTOCONSOLE, 0);
+    model.set_log_target("solver::gurobi").unwrap();
+    let x = model.add_var(GRBVar::builder().obj(1.0).ub(1.0).name("x".to_string()));
+    model.add_constr((&x + 0.0).le(1.0).name("c"));
+    model.optimize();
+
+    let records = LOGGER.records.lock().unwrap().clone();
+    let gurobi: Vec<_> = records
+        .iter()
+        .filter(|(target, _)| target == "solver::gurobi")
+        .collect();
+    assert!(!gurobi.is_empty());
+    assert!(gurobi
+        .iter()
+        .any(|(_, msg)| msg.contains("Optimal objective")));
+    // one record per line
+    assert!(gurobi.iter().all(|(_, msg)| !msg.contains('\n')));
+
+    // no more records once forwarding is stopped
+    model.clear_log_target().unwrap();
+    let count = LOGGER.records.lock().unwrap().len();
+    model.optimize();
+    assert_eq!(LOGGER.records.lock().unwrap().len(), count);
+}
@@ -3,7 +3,7 @@ use gurobirs::prelude::*;
 #[test]
 fn test_mip1() {
     let env = GRBenv::new(false, None).unwrap();
-    let mut model = GRBModel::new(env);
+    let mut model = GRBModel::new(&env);
     let x = model.add_var(
         GRBVar::builder()
             .lb(0.0)
@@ -28,15 +28,30 @@ fn test_mip1() {
     model.set_objective(&x + &y + 2.0 * &z, GRBModelSense::MAXIMIZE);
     let cons1 = model.add_constr((&x + 2.0 * &y + 3.0 * &z).le(4.0).name("c0"));
     model.add_constr((&x + &y).ge(1.0).name("c1"));
-    model.optimize();
+    let outcome = model.optimize();
+    assert_eq!(outcome.status, GRBStatus::OPTIMAL);
+    assert_eq!(outcome.obj_val, Some(3.0));
+    assert_eq!(model.status(), GRBStatus::OPTIMAL);
 
-    println!("{}: {}", x.get(GRBStrAttr::VARNAME), x.get(GRBDblAttr::X));
-    println!("{}: {}", y.get(GRBStrAttr::VARNAME), y.get(GRBDblAttr::X));
-    println!("{}: {}", z.get(GRBStrAttr::VARNAME), z.get(GRBDblAttr::X));
     println!(
         "{}: {}",
-        cons1.get(GRBStrAttr::CONSTRNAME),
-        cons1.get(GRBDblAttr::SLACK)
+        x.get(GRBStrAttr::VARNAME).unwrap(),
+        x.get(GRBDblAttr::X).unwrap()
     );
-    println!("Obj: {}", model.get(GRBDblAttr::OBJVAL));
+    println!(
+        "{}: {}",
+        y.get(GRBStrAttr::VARNAME).unwrap(),
+        y.get(GRBDblAttr::X).unwrap()
+    );
+    println!(
+        "{}: {}",
+        z.get(GRBStrAttr::VARNAME).unwrap(),
+        z.get(GRBDblAttr::X).unwrap()
+    );
+    println!(
+        "{}: {}",
+        cons1.get(GRBStrAttr::CONSTRNAME).unwrap(),
+        cons1.get(GRBDblAttr::SLACK).unwrap()
+    );
+    println!("Obj: {}", model.get(GRBDblAttr::OBJVAL).unwrap());
 }
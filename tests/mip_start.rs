@@ -0,0 +1,73 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::knapsack;
+
+#[test]
+fn test_multiple_partial_starts() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, vars) = knapsack(&env);
+    assert_eq!(model.num_mip_starts().unwrap(), 0);
+
+    assert_eq!(model.add_mip_start(&[(&vars[0], 1.0)]).unwrap(), 0);
+    assert_eq!(
+        model
+            .add_mip_start(&[(&vars[1], 1.0), (&vars[2], 1.0)])
+            .unwrap(),
+        1
+    );
+    assert_eq!(model.num_mip_starts().unwrap(), 2);
+    assert_eq!(model.mip_start(0).unwrap(), vec![Some(1.0), None, None]);
+    assert_eq!(
+        model.mip_start(1).unwrap(),
+        vec![None, Some(1.0), Some(1.0)]
+    );
+
+    // round trip through an .mst file
+    let path = std::env::temp_dir().join("gurobirs_mip_start.mst");
+    let path = path.to_str().unwrap();
+    model.write(path).unwrap();
+    model.clear_mip_starts().unwrap();
+    assert_eq!(model.num_mip_starts().unwrap(), 0);
+    model.read(path).unwrap();
+    assert_eq!(model.num_mip_starts().unwrap(), 2);
+    assert_eq!(model.mip_start(0).unwrap(), vec![Some(1.0), None, None]);
+    assert_eq!(
+        model.mip_start(1).unwrap(),
+        vec![None, Some(1.0), Some(1.0)]
+    );
+
+    let outcome = model.optimize();
+    assert_eq!(outcome.obj_val, Some(3.0));
+}
+
+#[test]
+fn test_start_from_solution() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _) = knapsack(&env);
+    model.optimize();
+    let solution = model.solution().unwrap();
+
+    // a modified model, with an extra variable that has no value in the solution
+    let (mut modified, mut vars) = knapsack(&env);
+    vars.push(modified.add_var(GRBVar::builder().obj(1.0).name("w".to_string())));
+    let (number, matched) = modified.add_mip_start_from_solution(&solution).unwrap();
+    assert_eq!(number, 0);
+    assert_eq!(matched, 3);
+    let start = modified.mip_start(0).unwrap();
+    assert_eq!(
+        start[..3],
+        solution.x.iter().map(|v| Some(*v)).collect::<Vec<_>>()[..]
+    );
+    assert_eq!(start[3], None);
+}
+
+#[test]
+fn test_start_with_variable_of_other_model() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _) = knapsack(&env);
+    let (_other, other_vars) = knapsack(&env);
+    let error = model.add_mip_start(&[(&other_vars[0], 1.0)]).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+    assert_eq!(model.num_mip_starts().unwrap(), 0);
+}
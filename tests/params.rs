@@ -0,0 +1,248 @@
+use gurobirs::prelude::*;
+
+#[test]
+fn test_model_params_do_not_leak_into_env() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set(GRBDblParam::TIMELIMIT, 100.0).unwrap();
+    let mut model_a = GRBModel::new(&env);
+    let mut model_b = GRBModel::new(&env);
+
+    // both models copied the value from the environment
+    assert_eq!(model_a.get_param(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+    assert_eq!(model_b.get_param(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+
+    let mut params = model_a.params();
+    assert_eq!(params.scope(), ParamScope::Model);
+    params.set(GRBDblParam::TIMELIMIT, 10.0).unwrap();
+
+    // only model_a changed
+    assert_eq!(model_a.params().get(GRBDblParam::TIMELIMIT).unwrap(), 10.0);
+    assert_eq!(model_b.params().get(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+
+    // the blanket `ModelSetter` impl uses the same model scope
+    model_b.set(GRBDblParam::TIMELIMIT, 20.0);
+    assert_eq!(model_b.get_param(GRBDblParam::TIMELIMIT).unwrap(), 20.0);
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+
+    // resetting a model resets to the defaults, not to the environment values
+    model_a.params().reset().unwrap();
+    let info = model_a.param_info(GRBDblParam::TIMELIMIT).unwrap();
+    assert_eq!(info.current, info.default);
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 100.0);
+    assert_eq!(env.params().scope(), ParamScope::Env);
+}
+
+#[test]
+fn test_typed_params() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set_typed(Presolve::Aggressive).unwrap();
+    assert_eq!(env.get_typed::<Presolve>().unwrap(), Presolve::Aggressive);
+    assert_eq!(env.get(GRBIntParam::PRESOLVE).unwrap(), 2);
+
+    let mut model = GRBModel::new(&env);
+    assert_eq!(model.get_typed::<Presolve>().unwrap(), Presolve::Aggressive);
+    model.set_typed(Method::Barrier).unwrap();
+    model.params().set_typed(MIPFocus::BestBound).unwrap();
+    assert_eq!(model.get_typed::<Method>().unwrap(), Method::Barrier);
+    assert_eq!(
+        model.params().get_typed::<MIPFocus>().unwrap(),
+        MIPFocus::BestBound
+    );
+
+    // the raw path still works and round-trips through the typed values
+    model.set(GRBIntParam::CUTS, 3);
+    assert_eq!(model.get_typed::<Cuts>().unwrap(), Cuts::VeryAggressive);
+    assert_eq!(i32::from(Cuts::Off), 0);
+    assert_eq!(
+        Method::try_from(42).unwrap_err().code,
+        gurobirs_sys::GRB_ERROR_VALUE_OUT_OF_RANGE
+    );
+}
+
+#[test]
+fn test_param_set() {
+    let mut set = ParamSet::new();
+    set.set_typed(Method::Barrier)
+        .set_dbl(GRBDblParam::TIMELIMIT, 60.0)
+        .set_dbl(GRBDblParam::MIPGAP, 0.01)
+        .set_int(GRBIntParam::THREADS, 2);
+    // setting a parameter again replaces the value
+    set.set_int(GRBIntParam::THREADS, 4);
+    assert_eq!(set.len(), 4);
+    assert_eq!(set.get_int(GRBIntParam::THREADS), Some(4));
+
+    // sets are compared regardless of the order of the assignments
+    let mut reordered = ParamSet::new();
+    reordered
+        .set_int(GRBIntParam::THREADS, 4)
+        .set_dbl(GRBDblParam::MIPGAP, 0.01)
+        .set_dbl(GRBDblParam::TIMELIMIT, 60.0)
+        .set_typed(Method::Barrier);
+    assert_eq!(reordered, set);
+    reordered.set_dbl(GRBDblParam::MIPGAP, 0.02);
+    assert_ne!(reordered, set);
+
+    let mut env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    set.apply_to_model(&mut model).unwrap();
+    assert_eq!(model.get_typed::<Method>().unwrap(), Method::Barrier);
+    assert_eq!(model.get_param(GRBDblParam::TIMELIMIT).unwrap(), 60.0);
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 1e100);
+
+    // everything that was applied shows up as non-default, and the diff drops default values
+    assert_eq!(ParamSet::from_params(&model.params()), set);
+    set.set_int(GRBIntParam::PRESOLVE, -1);
+    assert_eq!(set.non_default(&env.params()).unwrap().len(), 4);
+
+    // the pure-Rust .prm round-trip matches what gurobi reads
+    let prm = set.to_prm();
+    assert_eq!(ParamSet::from_prm(&prm).unwrap(), set);
+    let path = std::env::temp_dir().join("gurobirs_param_set.prm");
+    std::fs::write(&path, prm).unwrap();
+    env.read_params(path.to_str().unwrap()).unwrap();
+    assert_eq!(env.get(GRBIntParam::THREADS).unwrap(), 4);
+    assert_eq!(env.get(GRBDblParam::MIPGAP).unwrap(), 0.01);
+
+    // names are matched case-insensitively
+    let parsed = ParamSet::from_prm("# comment\nmipgap 0.5\n\nMETHOD  1\n").unwrap();
+    assert_eq!(parsed.get_dbl(GRBDblParam::MIPGAP), Some(0.5));
+    assert_eq!(parsed.get_int(GRBIntParam::METHOD), Some(1));
+    // `#` only starts a comment at the start of a line or word
+    let parsed = ParamSet::from_prm("#Threads 2\nLogFile run#1.log # the log\n").unwrap();
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed.get_str(GRBStrParam::LOGFILE), Some("run#1.log"));
+    let error = ParamSet::from_prm("NoSuchParam 1").unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_UNKNOWN_PARAMETER);
+    assert_eq!(error.message, "line 1: unknown parameter NoSuchParam");
+    let error = ParamSet::from_prm("Threads 1.5").unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_param_set_serde() {
+    let mut set = ParamSet::new();
+    set.set_typed(Method::Barrier)
+        .set_dbl(GRBDblParam::TIMELIMIT, 60.0)
+        .set_str(GRBStrParam::LOGFILE, "gurobi.log");
+    let json = serde_json::to_string(&set).unwrap();
+    assert_eq!(
+        json,
+        r#"{"Method":2,"TimeLimit":60.0,"LogFile":"gurobi.log"}"#
+    );
+    assert_eq!(serde_json::from_str::<ParamSet>(&json).unwrap(), set);
+    // integral values are accepted for double parameters
+    let parsed: ParamSet = serde_json::from_str(r#"{"timelimit": 60}"#).unwrap();
+    assert_eq!(parsed.get_dbl(GRBDblParam::TIMELIMIT), Some(60.0));
+}
+
+#[test]
+fn test_params_by_name() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set_param_str("TimeLimit", "60").unwrap();
+    env.set_param_str("threads", "2").unwrap();
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 60.0);
+    assert_eq!(env.get(GRBIntParam::THREADS).unwrap(), 2);
+    assert!(env.set_param_str("NoSuchParam", "1").is_err());
+    assert!(env.set_param_str("Threads", "many").is_err());
+
+    let mut model = GRBModel::new(&env);
+    model.params().set_param_str("MIPGap", "0.1").unwrap();
+    assert_eq!(model.get_param(GRBDblParam::MIPGAP).unwrap(), 0.1);
+
+    assert!(matches!("mipfocus".parse(), Ok(GRBIntParam::MIPFOCUS)));
+    assert!(matches!("TIMELIMIT".parse(), Ok(GRBDblParam::TIMELIMIT)));
+    assert!(matches!("LogFile".parse(), Ok(GRBStrParam::LOGFILE)));
+    let error = "TimeLimit".parse::<GRBIntParam>().unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_UNKNOWN_PARAMETER);
+
+    let names: Vec<_> = param_names().collect();
+    assert!(names.contains(&"TimeLimit"));
+    assert!(names.contains(&"Method"));
+    assert_eq!(
+        names.len(),
+        GRBIntParam::ALL.len() + GRBDblParam::ALL.len() + GRBStrParam::ALL.len()
+    );
+}
+
+#[test]
+fn test_env_getters() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set(GRBIntParam::THREADS, 2).unwrap();
+    env.set(GRBDblParam::MIPGAP, 0.05).unwrap();
+    env.set(GRBStrParam::LOGFILE, "gurobirs_getters.log".to_string())
+        .unwrap();
+    assert_eq!(env.get(GRBIntParam::THREADS).unwrap(), 2);
+    assert_eq!(env.get(GRBDblParam::MIPGAP).unwrap(), 0.05);
+    assert_eq!(
+        env.get(GRBStrParam::LOGFILE).unwrap(),
+        "gurobirs_getters.log"
+    );
+    // out of range values are rejected and leave the value unchanged
+    let error = env.set(GRBIntParam::PRESOLVE, 7).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_VALUE_OUT_OF_RANGE);
+    assert_eq!(env.get(GRBIntParam::PRESOLVE).unwrap(), -1);
+}
+
+#[test]
+fn test_param_info() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set(GRBIntParam::PRESOLVE, 2).unwrap();
+    let info = env.param_info(GRBIntParam::PRESOLVE).unwrap();
+    assert_eq!(
+        info,
+        ParamInfo {
+            current: 2,
+            min: Some(-1),
+            max: Some(2),
+            default: -1,
+        }
+    );
+
+    let info = env.param_info(GRBDblParam::MIPGAP).unwrap();
+    assert_eq!(info.current, 1e-4);
+    assert_eq!(info.default, 1e-4);
+    assert_eq!(info.min, Some(0.0));
+
+    // string parameters have no range
+    let info = env.param_info(GRBStrParam::LOGFILE).unwrap();
+    assert_eq!(info.min, None);
+    assert_eq!(info.max, None);
+    assert_eq!(info.default, "");
+
+    // the same information is available through the parameter handle
+    assert_eq!(env.params().info(GRBIntParam::PRESOLVE).unwrap().current, 2);
+}
+
+#[test]
+fn test_reset_params() {
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set(GRBIntParam::THREADS, 2).unwrap();
+    env.set(GRBDblParam::TIMELIMIT, 10.0).unwrap();
+    env.reset_params().unwrap();
+    assert_eq!(env.get(GRBIntParam::THREADS).unwrap(), 0);
+    assert_eq!(env.get(GRBDblParam::TIMELIMIT).unwrap(), 1e100);
+}
+
+#[test]
+fn test_write_read_params() {
+    let path = std::env::temp_dir().join("gurobirs_write_read.prm");
+    let path = path.to_str().unwrap();
+
+    let mut env = GRBenv::new(false, None).unwrap();
+    env.set(GRBIntParam::THREADS, 3).unwrap();
+    env.set(GRBDblParam::MIPGAP, 0.02).unwrap();
+    env.write_params(path).unwrap();
+
+    // only the non-default values are written
+    let written = ParamSet::from_prm(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(written.get_int(GRBIntParam::THREADS), Some(3));
+    assert_eq!(written.get_dbl(GRBDblParam::MIPGAP), Some(0.02));
+
+    let mut other = GRBenv::new(false, None).unwrap();
+    other.read_params(path).unwrap();
+    assert_eq!(other.get(GRBIntParam::THREADS).unwrap(), 3);
+    assert_eq!(other.get(GRBDblParam::MIPGAP).unwrap(), 0.02);
+    assert!(other.read_params("no_such_file.prm").is_err());
+}
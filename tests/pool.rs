@@ -0,0 +1,43 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::knapsack;
+
+#[test]
+fn test_solution_pool() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, vars) = knapsack(&env);
+    model.optimize();
+
+    let count = model.get(GRBIntAttr::SOLCOUNT).unwrap() as usize;
+    let pool = model.solution_pool().unwrap();
+    assert_eq!(pool.len(), count);
+    let solutions = pool.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(solutions[0].number, 0);
+    assert_eq!(solutions[0].obj_val, 3.0);
+    assert_eq!(solutions[0][&vars[2]], 1.0);
+    assert!(solutions.windows(2).all(|w| w[0].obj_val >= w[1].obj_val));
+    // reading the pool leaves the model on the best solution
+    assert_eq!(model.get_param(GRBIntParam::SOLUTIONNUMBER).unwrap(), 0);
+}
+
+#[test]
+fn test_optimize_k_best() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, vars) = knapsack(&env);
+    let solutions = model.optimize_k_best(3).unwrap();
+
+    // the three best: {x, z} and {y, z} with 3, then {z} or {x, y} with 2
+    assert_eq!(solutions.len(), 3);
+    let objs: Vec<f64> = solutions.iter().map(|s| s.obj_val).collect();
+    assert_eq!(objs, vec![3.0, 3.0, 2.0]);
+    assert!(solutions[..2].iter().all(|s| s.value(&vars[2]) == 1.0));
+}
+
+#[test]
+fn test_optimize_k_best_out_of_range() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _) = knapsack(&env);
+    let error = model.optimize_k_best(usize::MAX).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_VALUE_OUT_OF_RANGE);
+}
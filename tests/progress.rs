@@ -0,0 +1,98 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use gurobirs::prelude::*;
+
+// max sum v_i x_i  s.t.  sum w_i x_i <= capacity,  x binary
+fn knapsack_40(env: &GRBenv) -> (GRBModel, GRBVar) {
+    let mut model = GRBModel::new(env);
+    model.set(GRBIntParam::OUTPUTFLAG, 0);
+    let mut weight = GRBLinExpr::new();
+    let mut first = None;
+    for i in 0..40 {
+        let x = model.add_var(
+            GRBVar::builder()
+                .obj(((i * 37) % 23 + 5) as f64)
+                .vtype(GRBVarType::BINARY)
+                .name(format!("x{}", i)),
+        );
+        weight += &x * ((i * 17) % 19 + 3) as f64;
+        first.get_or_insert(x);
+    }
+    model.add_constr(weight.le(100.0).name("capacity"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    (model, first.unwrap())
+}
+
+#[test]
+fn test_progress_snapshots() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _) = knapsack_40(&env);
+    let interval = Duration::from_millis(1);
+    let mut snapshots = Vec::new();
+    let outcome = model
+        .optimize_with_progress(interval, |p| snapshots.push((Instant::now(), p)))
+        .unwrap();
+    assert_eq!(outcome.status, GRBStatus::OPTIMAL);
+    assert!(snapshots.iter().any(|(_, p)| p.phase == ProgressPhase::Mip));
+
+    // snapshots delivered during the solve are throttled, the last one may have been held back
+    // and delivered right after the solve
+    let during_solve = &snapshots[..snapshots.len() - 1];
+    for pair in during_solve.windows(2) {
+        assert!(pair[1].0.duration_since(pair[0].0) >= interval);
+    }
+    for pair in snapshots.windows(2) {
+        assert!(pair[0].1.runtime <= pair[1].1.runtime);
+    }
+
+    // the final snapshot describes the end of the solve
+    let (_, last) = snapshots.last().unwrap();
+    assert_eq!(last.phase, ProgressPhase::Mip);
+    assert_eq!(last.incumbent, outcome.obj_val);
+    assert_eq!(last.sol_count, outcome.sol_count);
+    assert!(last.runtime <= outcome.runtime);
+}
+
+struct CountingCallback<'a> {
+    calls: &'a Cell<usize>,
+}
+
+impl CallbackTrait for CountingCallback<'_> {
+    fn callback(&self, _: GRBCallbackContext) {
+        self.calls.set(self.calls.get() + 1);
+    }
+}
+
+#[test]
+fn test_progress_restores_callback() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x) = knapsack_40(&env);
+    let calls = Cell::new(0);
+    let mut callback = GRBCallback::new(CountingCallback { calls: &calls });
+    model.set_callback(&mut callback);
+
+    // the progress callback replaces the registered one during the solve
+    model
+        .optimize_with_progress(Duration::ZERO, |_| {})
+        .unwrap();
+    assert_eq!(calls.get(), 0);
+
+    // and the registered one is back afterwards
+    x.set(GRBDblAttr::OBJ, 100.0);
+    model.optimize();
+    assert!(calls.get() > 0);
+}
+
+#[test]
+fn test_progress_error() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().name("x".to_string()));
+    model.set_objective(-1.0 * (&x * &x), GRBModelSense::MINIMIZE);
+    model.set(GRBIntParam::NONCONVEX, 0);
+    let error = model
+        .optimize_with_progress(Duration::ZERO, |_| {})
+        .unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_Q_NOT_PSD);
+}
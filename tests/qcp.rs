@@ -6,7 +6,7 @@ use gurobirs::prelude::{
 #[test]
 fn test_qcp() {
     let env = GRBenv::new(false, None).unwrap();
-    let mut model = GRBModel::new(env);
+    let mut model = GRBModel::new(&env);
     let x = model.add_var(GRBVar::builder().name("x".to_owned()));
     let y = model.add_var(GRBVar::builder().name("y".to_owned()));
     let z = model.add_var(GRBVar::builder().name("z".to_owned()));
@@ -19,9 +19,21 @@ fn test_qcp() {
     model.add_qconstr((&x * &x - &y * &z).le(0.0).name("qc1"));
 
     model.optimize();
-    println!("{} {}", x.get(GRBStrAttr::VARNAME), x.get(GRBDblAttr::X));
-    println!("{} {}", y.get(GRBStrAttr::VARNAME), y.get(GRBDblAttr::X));
-    println!("{} {}", z.get(GRBStrAttr::VARNAME), z.get(GRBDblAttr::X));
+    println!(
+        "{} {}",
+        x.get(GRBStrAttr::VARNAME).unwrap(),
+        x.get(GRBDblAttr::X).unwrap()
+    );
+    println!(
+        "{} {}",
+        y.get(GRBStrAttr::VARNAME).unwrap(),
+        y.get(GRBDblAttr::X).unwrap()
+    );
+    println!(
+        "{} {}",
+        z.get(GRBStrAttr::VARNAME).unwrap(),
+        z.get(GRBDblAttr::X).unwrap()
+    );
 
-    println!("Obj: {}", model.get(GRBDblAttr::OBJVAL));
+    println!("Obj: {}", model.get(GRBDblAttr::OBJVAL).unwrap());
 }
@@ -0,0 +1,54 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::small_lp;
+
+#[test]
+fn test_sensitivity_report() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_lp(&env, false);
+    assert!(model.sensitivity().is_err());
+    model.optimize();
+
+    let report = model.sensitivity().unwrap();
+    assert_eq!(report.vars.len(), 2);
+    let sx = report.var(&x).unwrap();
+    assert_eq!(sx.name, "x");
+    assert_eq!(sx.value, 1.0);
+    // x stays basic as long as its objective coefficient is between 0 and that of y
+    assert_eq!((sx.obj_low, sx.obj_up), (0.0, 2.0));
+    assert_eq!(report.var(&y).unwrap().reduced_cost, 1.0);
+
+    let sc = report.constr(&c).unwrap();
+    assert_eq!(sc.name, "c");
+    assert_eq!(sc.dual, 1.0);
+    assert_eq!(sc.slack, 0.0);
+    // below 3 the bound on y makes x leave the basis, above that nothing changes
+    assert_eq!(sc.rhs_low, 3.0);
+    assert!(sc.rhs_up >= 1e100);
+
+    // quadratic constraints have no sensitivity information
+    let mut qcp = GRBModel::new(&env);
+    let z = qcp.add_var(GRBVar::builder().name("z".to_string()));
+    let q = qcp.add_qconstr((&z * &z).le(1.0).name("q"));
+    assert_eq!(q.index, c.index);
+    assert!(report.constr(&q).is_none());
+    // nor do variables beyond the ones of the report
+    qcp.add_var(GRBVar::builder().name("u".to_string()));
+    let w = qcp.add_var(GRBVar::builder().name("w".to_string()));
+    assert!(report.var(&w).is_none());
+}
+
+#[test]
+fn test_sensitivity_mip() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, _, _) = small_lp(&env, true);
+    model.optimize();
+    let error = model.sensitivity().unwrap_err();
+    assert!(error.message.contains("fixed()"));
+
+    let mut fixed = model.fixed().unwrap();
+    fixed.model.optimize();
+    let report = fixed.model.sensitivity().unwrap();
+    assert_eq!(report.var(&fixed.var(&x).unwrap()).unwrap().value, 1.0);
+}
@@ -0,0 +1,80 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::small_lp;
+
+#[test]
+fn test_solution_snapshot() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, x, y, c) = small_lp(&env, false);
+    assert!(model.solution().is_err());
+    model.optimize();
+
+    let solution = model.solution().unwrap();
+    assert_eq!(solution.status, GRBStatus::OPTIMAL);
+    assert_eq!(solution.obj_val, 7.0);
+    assert_eq!(solution[&x], 1.0);
+    assert_eq!(solution.value(&y), 3.0);
+    assert_eq!(solution.value_by_name("y"), Some(3.0));
+    assert_eq!(solution.slack(&c), Some(0.0));
+    assert_eq!(solution[&c], 0.0);
+    assert_eq!(solution.dual(&c), Some(1.0));
+    assert_eq!(solution.reduced_cost(&y), Some(1.0));
+    assert_eq!(solution.obj_bound, None);
+
+    // the snapshot is detached from the model
+    y.set(GRBDblAttr::UB, 1.0);
+    model.optimize();
+    let changed = model.solution().unwrap();
+    assert_eq!(changed[&y], 1.0);
+    assert_eq!(solution[&y], 3.0);
+    assert_ne!(changed, solution);
+    drop(model);
+    assert_eq!(
+        solution.iter().collect::<Vec<_>>(),
+        vec![("x", 1.0), ("y", 3.0)]
+    );
+}
+
+#[test]
+fn test_solution_with_quadratic_constraints() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().obj(1.0).name("x".to_string()));
+    let y = model.add_var(GRBVar::builder().obj(1.0).name("y".to_string()));
+    let c0 = model.add_constr((1.0 * &x).le(3.0).name("c0"));
+    let q = model.add_qconstr((&x * &x + &y * &y).le(8.0).name("q"));
+    let c1 = model.add_constr((1.0 * &y).le(1.5).name("c1"));
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    // linear and quadratic constraints are numbered separately
+    assert_eq!((c0.index, q.index, c1.index), (0, 0, 1));
+    assert_eq!(q.kind(), GRBAttrObject::QConstr);
+    model.optimize();
+
+    let solution = model.solution().unwrap();
+    assert_eq!(solution.slack.len(), 2);
+    assert!(solution[&c1].abs() < 1e-6);
+    assert!(solution.slack(&c0).unwrap() > 0.5);
+    assert_eq!(solution.slack(&q), None);
+    // duals of QCPs need `QCPDual`
+    assert_eq!(solution.pi, None);
+    assert_eq!(solution.dual(&c1), None);
+
+    model.set(GRBIntParam::QCPDUAL, 1);
+    model.optimize();
+    let solution = model.solution().unwrap();
+    assert!(solution.dual(&c1).unwrap() > 0.0);
+    assert_eq!(solution.dual(&q), None);
+    assert!(solution.reduced_cost(&x).is_some());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_solution_serde() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut model, _, _, _) = small_lp(&env, false);
+    model.optimize();
+    let solution = model.solution().unwrap();
+    let json = serde_json::to_string(&solution).unwrap();
+    assert_eq!(serde_json::from_str::<Solution>(&json).unwrap(), solution);
+}
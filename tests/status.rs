@@ -0,0 +1,43 @@
+use gurobirs::prelude::*;
+
+#[test]
+fn test_infeasible_outcome() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    assert_eq!(model.status(), GRBStatus::LOADED);
+    let x = model.add_var(GRBVar::builder().lb(0.0).ub(1.0));
+    model.add_constr((1.0 * &x).ge(2.0));
+    model.set(GRBIntParam::PRESOLVE, 0);
+
+    let outcome = model.optimize();
+    assert_eq!(outcome.status, GRBStatus::INFEASIBLE);
+    assert!(!outcome.has_solution());
+    assert_eq!(outcome.obj_val, None);
+    assert_eq!(outcome.mip_gap, None);
+}
+
+#[test]
+fn test_try_optimize_error() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    let x = model.add_var(GRBVar::builder().name("x".to_string()));
+    // an indefinite objective cannot be minimized
+    model.set_objective(-1.0 * (&x * &x), GRBModelSense::MINIMIZE);
+    model.set(GRBIntParam::NONCONVEX, 0);
+    let error = model.try_optimize().unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_Q_NOT_PSD);
+}
+
+#[test]
+fn test_status_conversion() {
+    for status in [
+        GRBStatus::OPTIMAL,
+        GRBStatus::TIME_LIMIT,
+        GRBStatus::INFEASIBLE,
+    ] {
+        let code: i32 = status.into();
+        assert_eq!(GRBStatus::try_from(code), Ok(status));
+    }
+    let error = GRBStatus::try_from(-42).unwrap_err();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_VALUE_OUT_OF_RANGE);
+}
@@ -0,0 +1,48 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use gurobirs::prelude::*;
+
+mod common;
+use common::market_split;
+
+#[test]
+fn test_terminate_from_other_thread() {
+    fn assert_send_sync<T: Send + Sync + Clone>(_: &T) {}
+
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = market_split(&env);
+    let handle = model.terminate_handle();
+    assert_send_sync(&handle);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let stopper = {
+        let done = done.clone();
+        thread::spawn(move || {
+            // keep asking until the solve has picked it up
+            while !done.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(50));
+                handle.terminate();
+            }
+        })
+    };
+    let outcome = model.optimize();
+    done.store(true, Ordering::SeqCst);
+    stopper.join().unwrap();
+    assert_eq!(outcome.status, GRBStatus::INTERRUPTED);
+    assert!(outcome.runtime < 60.0);
+}
+
+#[test]
+fn test_terminate_after_model_dropped() {
+    let env = GRBenv::new(false, None).unwrap();
+    let model = market_split(&env);
+    let handle = model.terminate_handle();
+    let other = handle.clone();
+    drop(model);
+    // does nothing, the model is gone
+    handle.terminate();
+    thread::spawn(move || other.terminate()).join().unwrap();
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use gurobirs::prelude::*;
+
+mod common;
+use common::market_split;
+
+fn progress(node_count: f64, runtime: f64, incumbent: Option<f64>) -> Progress {
+    Progress {
+        phase: ProgressPhase::Mip,
+        runtime,
+        incumbent,
+        bound: None,
+        gap: None,
+        node_count,
+        open_node_count: 0.0,
+        iter_count: 0.0,
+        sol_count: incumbent.is_some() as i32,
+    }
+}
+
+#[test]
+fn test_stall_tolerance() {
+    let mut stall = Stall::nodes(100.0);
+    assert!(stall.check(&progress(0.0, 0.0, None)).is_none());
+    assert!(stall.check(&progress(10.0, 0.1, Some(50.0))).is_none());
+    // a change within the tolerance is not an improvement
+    assert!(stall
+        .check(&progress(60.0, 0.2, Some(50.0 + 1e-9)))
+        .is_none());
+    let reason = stall.check(&progress(110.0, 0.3, Some(50.0 + 2e-9)));
+    assert!(reason.is_some());
+
+    let mut stall = Stall::time(Duration::from_secs(1)).tolerance(0.1);
+    assert!(stall.check(&progress(0.0, 0.0, Some(100.0))).is_none());
+    assert!(stall.check(&progress(0.0, 0.9, Some(105.0))).is_none());
+    assert!(stall.check(&progress(0.0, 1.0, Some(120.0))).is_none());
+    assert!(stall.check(&progress(0.0, 1.5, Some(120.0))).is_none());
+    assert!(stall.check(&progress(0.0, 2.0, Some(121.0))).is_some());
+}
+
+#[test]
+fn test_combined_policies() {
+    let mut policy = GapAfter::new(0.05, Duration::from_secs(1)).and(Stall::nodes(10.0));
+    let mut at = |nodes, runtime, gap| {
+        let mut p = progress(nodes, runtime, Some(100.0));
+        p.gap = Some(gap);
+        policy.check(&p)
+    };
+    assert!(at(0.0, 0.0, 0.01).is_none());
+    // gap reached after the time limit, but the incumbent just stalled for 5 nodes
+    assert!(at(5.0, 2.0, 0.01).is_none());
+    assert!(at(10.0, 2.0, 0.01).is_some());
+
+    let mut policy = GapAfter::new(0.05, Duration::from_secs(1)).or(Stall::nodes(10.0));
+    assert!(policy.check(&progress(0.0, 0.0, Some(1.0))).is_none());
+    assert!(policy.check(&progress(10.0, 0.0, Some(1.0))).is_some());
+}
+
+#[test]
+fn test_objective_target_reads_model_sense() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = GRBModel::new(&env);
+    model.add_var(GRBVar::builder().obj(1.0).name("x".to_string()));
+
+    // minimization: reached once the incumbent is at most the target
+    let mut target = ObjectiveTarget::new(10.0);
+    target.prepare(&model);
+    assert!(target.check(&progress(0.0, 0.0, Some(12.0))).is_none());
+    assert!(target.check(&progress(0.0, 0.0, Some(8.0))).is_some());
+
+    model.set(GRBIntAttr::MODELSENSE, GRBModelSense::MAXIMIZE.into());
+    model.update().unwrap();
+    let mut target = ObjectiveTarget::new(10.0);
+    target.prepare(&model);
+    assert!(target.check(&progress(0.0, 0.0, Some(8.0))).is_none());
+    let reason = target.check(&progress(0.0, 0.0, Some(12.0))).unwrap();
+    assert!(reason.message.contains("reached target"));
+}
+
+#[test]
+fn test_policy_stops_solve() {
+    let env = GRBenv::new(false, None).unwrap();
+    let mut model = market_split(&env);
+    let outcome = model
+        .optimize_with_policy(WallClock::new(Duration::from_millis(500)))
+        .unwrap();
+    // the time limit of the model is 60s, the policy stops the solve long before
+    assert_eq!(outcome.status, GRBStatus::INTERRUPTED);
+    assert!(outcome.runtime < 30.0);
+    let reason = model.termination_reason().unwrap();
+    assert!(reason.message.contains("wall-clock budget of 0.5s used up"));
+
+    // the reason is reset by the next optimization
+    model.set(GRBDblParam::TIMELIMIT, 0.1);
+    model.optimize();
+    assert!(model.termination_reason().is_none());
+}
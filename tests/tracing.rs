@@ -0,0 +1,113 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use gurobirs::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+type Fields = Vec<(String, String)>;
+
+// records the target and the fields of every event
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<(String, Fields)>>>,
+}
+
+struct FieldVisitor(Fields);
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{:?}", value)));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = FieldVisitor(Vec::new());
+        event.record(&mut fields);
+        self.events
+            .lock()
+            .unwrap()
+            .push((event.metadata().target().to_string(), fields.0));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn field<'a>(fields: &'a Fields, name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, value)| value.as_str())
+}
+
+#[test]
+fn test_tracing_events() {
+    let recorder = Recorder::default();
+    let env = GRBenv::new(false, None).unwrap();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let mut model = GRBModel::new(&env);
+        model.set(GRBIntParam::LOGTOCONSOLE, 0);
+        model.set_log_target("solver").unwrap();
+        let x = model.add_var(GRBVar::builder().obj(1.0).ub(1.0).name("x".to_string()));
+        model.add_constr((&x + 0.0).le(1.0).name("c"));
+        model.add_qconstr((&x * &x).le(1.0).name("q"));
+        model.optimize();
+        // asynchronous optimizations are traced as well
+        model.optimize_async().unwrap().join().unwrap();
+    });
+
+    let events = recorder.events.lock().unwrap();
+    let optimize: Vec<&Fields> = events
+        .iter()
+        .filter(|(target, _)| target == "gurobirs")
+        .map(|(_, fields)| fields)
+        .collect();
+    let messages: Vec<_> = optimize.iter().map(|f| field(f, "message")).collect();
+    assert_eq!(
+        messages,
+        vec![
+            Some("optimize started"),
+            Some("optimize finished"),
+            Some("optimize started"),
+            Some("optimize finished"),
+        ]
+    );
+    // constraints of every kind are counted
+    assert_eq!(field(optimize[0], "num_constrs"), Some("Some(1)"));
+    assert_eq!(field(optimize[0], "num_qconstrs"), Some("Some(1)"));
+    assert_eq!(field(optimize[0], "num_genconstrs"), Some("Some(0)"));
+    let status: i32 = GRBStatus::OPTIMAL.into();
+    assert_eq!(
+        field(optimize[1], "status"),
+        Some(format!("Some({})", status).as_str())
+    );
+
+    // log lines carry the configured target in a field
+    let log_lines: Vec<_> = events
+        .iter()
+        .filter(|(target, _)| target == "gurobi")
+        .collect();
+    assert!(!log_lines.is_empty());
+    assert!(log_lines
+        .iter()
+        .all(|(_, fields)| field(fields, "log_target") == Some("\"solver\"")));
+}
@@ -0,0 +1,52 @@
+use gurobirs::prelude::*;
+
+mod common;
+use common::{knapsack, market_split};
+
+#[test]
+fn test_tune_model() {
+    let env = GRBenv::new(false, None).unwrap();
+    // hard enough for the tuner to find settings that beat the defaults
+    let mut model = market_split(&env);
+    model.set(GRBDblParam::TIMELIMIT, 2.0);
+    model.set(GRBDblParam::TUNETIMELIMIT, 20.0);
+    model.set(GRBIntParam::TUNEOUTPUT, 0);
+    model.set(GRBIntParam::TUNERESULTS, 1);
+
+    let results = model.tune().unwrap();
+    assert_eq!(results.len(), 1);
+    let results: Vec<ParamSet> = results.map(Result::unwrap).collect();
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert!(!result.is_empty());
+    // the settings made before tuning are not part of the result
+    assert_eq!(result.get_int(GRBIntParam::OUTPUTFLAG), None);
+    assert_eq!(result.get_dbl(GRBDblParam::TIMELIMIT), None);
+    assert_eq!(result.get_dbl(GRBDblParam::TUNETIMELIMIT), None);
+    assert_eq!(result.get_int(GRBIntParam::TUNERESULTS), None);
+    // the result can be written out and read back
+    assert_eq!(&ParamSet::from_prm(&result.to_prm()).unwrap(), result);
+}
+
+#[test]
+fn test_tune_models() {
+    let env = GRBenv::new(false, None).unwrap();
+    let (mut a, _) = knapsack(&env);
+    let (b, _) = knapsack(&env);
+    a.set(GRBIntParam::OUTPUTFLAG, 0);
+    a.set(GRBDblParam::TUNETIMELIMIT, 2.0);
+
+    let results = env.tune_models(&[&a, &b]).unwrap();
+    for result in results {
+        let result = result.unwrap();
+        assert_eq!(result.get_dbl(GRBDblParam::TUNETIMELIMIT), None);
+    }
+    let error = env.tune_models(&[]).err().unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+
+    // models of another environment are rejected
+    let other = GRBenv::new(false, None).unwrap();
+    let (c, _) = knapsack(&other);
+    let error = env.tune_models(&[&a, &c]).err().unwrap();
+    assert_eq!(error.code, gurobirs_sys::GRB_ERROR_INVALID_ARGUMENT);
+}